@@ -1,9 +1,10 @@
 import { queryClient } from "@/utils/api";
-import { Autofun, SEED_CONFIG } from "@/utils/program";
+import { Autofun, SEED_CONFIG, SEED_FEE_CONFIG } from "@/utils/program";
 import { Program } from "@coral-xyz/anchor";
 import { PublicKey } from "@solana/web3.js";
 // The query key should be consistent
 const configAccountQueryKey = ["configAccount"];
+const feeConfigAccountQueryKey = ["feeConfigAccount"];
 
 export const getConfigAccount = async (program: Program<Autofun>) => {
   return queryClient.ensureQueryData({
@@ -24,3 +25,25 @@ export const getConfigAccount = async (program: Program<Autofun>) => {
     staleTime: 1000 * 60 * 10, // 10 minutes
   });
 };
+
+// Swap fees live in their own account, apart from the config
+export const getFeeConfigAccount = async (program: Program<Autofun>) => {
+  return queryClient.ensureQueryData({
+    queryKey: feeConfigAccountQueryKey,
+    queryFn: async () => {
+      if (!program) {
+        throw new Error("missing program");
+      }
+
+      const [feeConfigPda, _] = PublicKey.findProgramAddressSync(
+        [Buffer.from(SEED_FEE_CONFIG)],
+        program.programId,
+      );
+      const feeConfigAccount =
+        await program.account.feeConfig.fetch(feeConfigPda);
+
+      return feeConfigAccount;
+    },
+    staleTime: 1000 * 60 * 10, // 10 minutes
+  });
+};
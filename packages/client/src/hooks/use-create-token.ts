@@ -82,14 +82,16 @@ const useCreateTokenMutation = () => {
               configAccount,
             )
           : await program.methods
-              .launch(
-                Number(env.decimals),
-                new BN(Number(env.tokenSupply)),
-                new BN(Number(env.virtualReserves)),
-                token_metadata.name,
-                token_metadata.symbol,
-                metadataUrl,
-              )
+              .launch({
+                decimals: Number(env.decimals),
+                tokenSupply: new BN(Number(env.tokenSupply)),
+                virtualLamportReserves: new BN(Number(env.virtualReserves)),
+                name: token_metadata.name,
+                symbol: token_metadata.symbol,
+                uri: metadataUrl,
+                tradingStartsAt: null,
+                expiresAt: null,
+              })
               .accounts({
                 creator: userPublicKey,
                 token: mintKeypair.publicKey,
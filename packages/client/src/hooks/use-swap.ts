@@ -12,7 +12,6 @@ import {
   VersionedTransaction,
 } from "@solana/web3.js";
 import { useState } from "react";
-import { getConfigAccount } from "./use-config-account";
 import { useMevProtection } from "./use-mev-protection";
 import { useSlippage } from "./use-slippage";
 import { useTransactionSpeed } from "./use-transaction-speed";
@@ -121,9 +120,7 @@ export const useSwap = () => {
           program.programId,
         );
         const curve = await program.account.bondingCurve.fetch(bondingCurvePda);
-        const config = await getConfigAccount(program);
-
-        const internalIx = await swapIx(
+        const internalIxs = await swapIx(
           wallet.publicKey,
           new PublicKey(tokenAddress),
           swapAmount,
@@ -132,9 +129,8 @@ export const useSwap = () => {
           program,
           curve.reserveToken.toNumber(),
           curve.reserveLamport.toNumber(),
-          config,
         );
-        ixs = [internalIx];
+        ixs = internalIxs;
 
         let solFee;
         switch (speed) {
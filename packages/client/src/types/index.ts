@@ -200,9 +200,12 @@ export type ConfigAccount = {
   pendingAuthority: PublicKey;
   teamWallet: PublicKey;
   initBondingCurve: number;
+  curveLimit: BN;
+};
+
+export type FeeConfigAccount = {
   platformBuyFee: BN;
   platformSellFee: BN;
-  curveLimit: BN;
 };
 
 // Type definitions for global objects
@@ -6,6 +6,7 @@ import { useMemo } from "react";
 
 export const SEED_CONFIG = "config";
 export const SEED_BONDING_CURVE = "bonding_curve";
+export const SEED_FEE_CONFIG = "fee_config";
 
 export const useProgram = () => {
   const wallet = useWallet();
@@ -1,8 +1,11 @@
-import { getConfigAccount } from "@/hooks/use-config-account";
-import { ConfigAccount } from "@/types";
+import { getFeeConfigAccount } from "@/hooks/use-config-account";
 // Import the type from the shared types package
 import { Autofun } from "@autodotfun/types/types/autofun.ts";
 import { BN, Program } from "@coral-xyz/anchor";
+import {
+  createAssociatedTokenAccountIdempotentInstruction,
+  getAssociatedTokenAddressSync,
+} from "@solana/spl-token";
 import { Connection, Keypair, PublicKey, Transaction } from "@solana/web3.js";
 import { env } from "./env"; // Import env
 // import { toast } from "react-toastify";
@@ -91,12 +94,16 @@ export const launchAndSwapTx = async (
 
   const tx = await program.methods
     .launchAndSwap(
-      decimals,
-      new BN(tokenSupply),
-      new BN(virtualLamportReserves),
-      name,
-      symbol,
-      uri,
+      {
+        decimals,
+        tokenSupply: new BN(tokenSupply),
+        virtualLamportReserves: new BN(virtualLamportReserves),
+        name,
+        symbol,
+        uri,
+        tradingStartsAt: null,
+        expiresAt: null,
+      },
       new BN(swapAmount),
       new BN(minOutput),
       new BN(deadline),
@@ -146,13 +153,13 @@ export const getSwapAmount = async (
   reserveToken: number,
   reserveLamport: number,
 ) => {
-  const configAccount = await getConfigAccount(program);
+  const feeConfigAccount = await getFeeConfigAccount(program);
 
   // Apply platform fee
   const feePercent =
     style === 1
-      ? Number(configAccount.platformSellFee)
-      : Number(configAccount.platformBuyFee);
+      ? Number(feeConfigAccount.platformSellFee)
+      : Number(feeConfigAccount.platformBuyFee);
   const adjustedAmount = Math.floor(
     (amount * (FEE_BASIS_POINTS - feePercent)) / FEE_BASIS_POINTS,
   );
@@ -243,7 +250,6 @@ export const swapIx = async (
   program: Program<Autofun>,
   reserveToken: number,
   reserveLamport: number,
-  configAccount: ConfigAccount,
 ) => {
   const estimatedOutputResult = await getSwapAmount(
     program,
@@ -260,17 +266,26 @@ export const swapIx = async (
 
   const deadline = Math.floor(Date.now() / 1000) + 120;
 
+  // swap takes any token account of the user, use the ATA and create it on a first buy
+  const userTokenAccount = getAssociatedTokenAddressSync(token, user);
+  const createAtaIx = createAssociatedTokenAccountIdempotentInstruction(
+    user,
+    userTokenAccount,
+    user,
+    token,
+  );
+
   // Apply the fee instruction to the transaction
-  const tx = await program.methods
+  const ix = await program.methods
     .swap(new BN(amount), style, minOutput, new BN(deadline))
     .accounts({
-      teamWallet: configAccount.teamWallet,
       user,
       tokenMint: token,
+      userTokenAccount,
     })
     .instruction();
 
-  return tx;
+  return [createAtaIx, ix];
 };
 
 /**
@@ -2,4 +2,22 @@ pub const CONFIG: &str = "config";
 pub const GLOBAL: &str = "global";
 pub const BONDING_CURVE: &str = "bonding_curve";
 pub const METADATA: &str = "metadata";
+pub const MINT: &str = "mint";
+pub const LAUNCH_COUNTER: &str = "launch_counter";
+pub const QUOTE_CONFIG: &str = "quote_config";
+pub const FEE_VAULT: &str = "fee_vault";
+pub const FEE_CONFIG: &str = "fee_config";
+pub const TRADER_RECORD: &str = "trader_record";
+pub const AUCTION: &str = "auction";
+pub const BID: &str = "bid";
+pub const ORDER: &str = "order";
 pub const LAMPORT_DECIMALS: u8 = 9;
+//  price observations kept per bonding curve for the twap
+pub const TWAP_OBSERVATIONS: usize = 8;
+//  seconds between two observations, so a burst of trades can't flush the ring
+//  the ring covers at least (TWAP_OBSERVATIONS - 1) * TWAP_OBSERVATION_INTERVAL seconds
+pub const TWAP_OBSERVATION_INTERVAL: i64 = 60;
+
+//  compute units swap_v2 is expected to stay under, checked by tests/swapComputeUnits.ts
+//  clients can request this as their compute unit limit for swap_v2 transactions
+pub const SWAP_V2_COMPUTE_BUDGET: u32 = 45_000;
@@ -48,4 +48,88 @@ pub enum PumpfunError {
 
     #[msg("Decimal overflow")]
     DecimalOverflow,
+
+    #[msg("Trading start time must be in the future")]
+    InvalidTradingStartTime,
+
+    #[msg("Trading has not started yet")]
+    TradingNotStarted,
+
+    #[msg("Launch nonce does not match the creator's launch counter")]
+    InvalidLaunchNonce,
+
+    #[msg("Quote mint is not enabled")]
+    QuoteMintDisabled,
+
+    #[msg("Quote mint does not match the bonding curve")]
+    IncorrectQuoteMint,
+
+    #[msg("Wrapped SOL swaps need the user and global wrapped SOL accounts, the native mint and the associated token program")]
+    MissingWsolAccount,
+
+    #[msg("Swap direction must be 0 (buy) or 1 (sell)")]
+    InvalidDirection,
+
+    #[msg("Recipient does not match the swap params")]
+    IncorrectRecipient,
+
+    #[msg("TWAP window must be greater than zero")]
+    InvalidTwapWindow,
+
+    #[msg("TWAP window is longer than the recorded observations")]
+    TwapWindowTooLong,
+
+    #[msg("Expiry must be in the future and after trading starts")]
+    InvalidExpiry,
+
+    #[msg("The curve expired before completing")]
+    CurveExpired,
+
+    #[msg("Refunds open once the curve expires without completing")]
+    CurveNotExpired,
+
+    #[msg("Auctions open before trading starts and close after they open")]
+    InvalidAuctionWindow,
+
+    #[msg("The auction commit window is closed")]
+    AuctionClosed,
+
+    #[msg("The auction commit window is still open")]
+    AuctionNotEnded,
+
+    #[msg("Trading opens once the auction is settled")]
+    AuctionNotSettled,
+
+    #[msg("The auction is already settled")]
+    AuctionAlreadySettled,
+
+    #[msg("The bid is already claimed")]
+    BidAlreadyClaimed,
+
+    #[msg("Orders need an amount, a limit price and a future expiry")]
+    InvalidOrder,
+
+    #[msg("The order has expired")]
+    OrderExpired,
+
+    #[msg("The curve price has not crossed the order's limit price")]
+    OrderNotTriggered,
+
+    #[msg("Bonding curve account has an unknown layout")]
+    InvalidBondingCurveLayout,
+
+    #[msg("Config has the legacy layout, run migrate_config first")]
+    ConfigNeedsMigration,
+
+    #[msg("The curve is still trading")]
+    CurveStillTrading,
+
+    #[msg("Sell orders need the order's token account")]
+    MissingOrderTokenAccount,
+
+    #[msg("Refunds are capped by the tokens the trader bought off the curve")]
+    RefundExceedsBought,
+
+    #[msg("The trader record still backs a refund, only the trader can close it")]
+    RefundPending,
 }
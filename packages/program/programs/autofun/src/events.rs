@@ -5,4 +5,72 @@ pub struct CompleteEvent {
     pub user: Pubkey,
     pub mint: Pubkey,
     pub bonding_curve: Pubkey,
-}
\ No newline at end of file
+}
+
+#[event]
+pub struct FeesSwept {
+    pub quote_mint: Pubkey,
+    pub team_wallet: Pubkey,
+    pub amount: u64,
+}
+
+#[event]
+pub struct Refunded {
+    pub user: Pubkey,
+    pub mint: Pubkey,
+    pub token_amount: u64, //  burned
+    pub refund_amount: u64, //  in the curve's quote units
+}
+
+#[event]
+pub struct AuctionBid {
+    pub auction: Pubkey,
+    pub bidder: Pubkey,
+    pub amount: u64,
+    pub total_bids: u64,
+}
+
+#[event]
+pub struct AuctionSettled {
+    pub auction: Pubkey,
+    pub mint: Pubkey,
+    pub total_bids: u64,
+    pub lamports_used: u64, //  fee included
+    pub tokens_out: u64,
+}
+
+#[event]
+pub struct AuctionClaimed {
+    pub auction: Pubkey,
+    pub bidder: Pubkey,
+    pub token_amount: u64,
+    pub refund_amount: u64,
+}
+
+#[event]
+pub struct OrderPlaced {
+    pub order: Pubkey,
+    pub owner: Pubkey,
+    pub mint: Pubkey,
+    pub direction: u8, //  0 buy, 1 sell
+    pub amount: u64,
+    pub limit_price: u64,
+    pub keeper_tip: u64,
+    pub expires_at: i64,
+}
+
+#[event]
+pub struct OrderFilled {
+    pub order: Pubkey,
+    pub owner: Pubkey,
+    pub keeper: Pubkey,
+    pub amount_in: u64,
+    pub amount_out: u64,
+    pub keeper_tip: u64,
+}
+
+#[event]
+pub struct OrderCancelled {
+    pub order: Pubkey,
+    pub owner: Pubkey,
+}
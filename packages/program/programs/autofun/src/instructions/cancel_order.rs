@@ -0,0 +1,94 @@
+use crate::{
+    constants::ORDER,
+    errors::*,
+    events::OrderCancelled,
+    state::{Direction, Order},
+    utils::token_transfer_with_signer,
+};
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Mint, Token, TokenAccount};
+
+//  Owner closes an unfilled order, getting back the escrowed SOL or tokens and the tip
+#[derive(Accounts)]
+pub struct CancelOrder<'info> {
+    #[account(
+        mut,
+        seeds = [ORDER.as_bytes(), order.bonding_curve.as_ref(), owner.key().as_ref(), &order.nonce.to_le_bytes()],
+        bump = order.bump,
+        has_one = owner @PumpfunError::IncorrectAuthority,
+        has_one = token_mint,
+        close = owner
+    )]
+    order: Box<Account<'info, Order>>,
+
+    token_mint: Box<Account<'info, Mint>>,
+
+    //  holds the tokens of a sell order, buy orders have none
+    #[account(
+        mut,
+        associated_token::mint = token_mint,
+        associated_token::authority = order
+    )]
+    order_token_account: Option<Box<Account<'info, TokenAccount>>>,
+
+    #[account(
+        mut,
+        token::mint = token_mint,
+        token::authority = owner
+    )]
+    owner_token_account: Box<Account<'info, TokenAccount>>,
+
+    #[account(mut)]
+    owner: Signer<'info>,
+
+    #[account(address = token::ID)]
+    token_program: Program<'info, Token>,
+}
+
+impl<'info> CancelOrder<'info> {
+    pub fn process(&mut self) -> Result<()> {
+        let order = &self.order;
+
+        let nonce = order.nonce.to_le_bytes();
+        let order_seeds: &[&[&[u8]]] = &[&[
+            ORDER.as_bytes(),
+            order.bonding_curve.as_ref(),
+            order.owner.as_ref(),
+            &nonce,
+            &[order.bump],
+        ]];
+
+        //  escrowed SOL and the tip return with the order account's lamports
+        if order.direction == Direction::Sell {
+            let Some(order_token_account) = &self.order_token_account else {
+                return err!(PumpfunError::MissingOrderTokenAccount);
+            };
+
+            token_transfer_with_signer(
+                order_token_account.to_account_info(),
+                order.to_account_info(),
+                self.owner_token_account.to_account_info(),
+                &self.token_program,
+                order_seeds,
+                order_token_account.amount,
+            )?;
+
+            token::close_account(CpiContext::new_with_signer(
+                self.token_program.to_account_info(),
+                token::CloseAccount {
+                    account: order_token_account.to_account_info(),
+                    destination: self.owner.to_account_info(),
+                    authority: order.to_account_info(),
+                },
+                order_seeds,
+            ))?;
+        }
+
+        emit!(OrderCancelled {
+            order: order.key(),
+            owner: order.owner,
+        });
+
+        Ok(())
+    }
+}
@@ -0,0 +1,137 @@
+use crate::{
+    constants::{AUCTION, BID, BONDING_CURVE, GLOBAL, TRADER_RECORD},
+    errors::*,
+    events::AuctionClaimed,
+    state::{Auction, Bid, BondingCurve, Direction, TraderRecord},
+    utils::{sol_transfer_from_program_account, token_transfer_with_signer},
+};
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Mint, Token, TokenAccount};
+
+//  Pays a bidder their tokens and unspent SOL of a settled auction, and returns the bid's rent
+//  if the curve expired before settlement, the whole bid is refunded
+//  the tokens are credited to the bidder's trader record so they can be refunded if the curve expires
+#[derive(Accounts)]
+pub struct ClaimAuction<'info> {
+    token_mint: Box<Account<'info, Mint>>,
+
+    #[account(
+        seeds = [BONDING_CURVE.as_bytes(), &token_mint.key().to_bytes()],
+        bump = bonding_curve.load()?.bump
+    )]
+    bonding_curve: AccountLoader<'info, BondingCurve>,
+
+    #[account(
+        mut,
+        seeds = [AUCTION.as_bytes(), bonding_curve.key().as_ref()],
+        bump = auction.bump
+    )]
+    auction: Box<Account<'info, Auction>>,
+
+    #[account(
+        mut,
+        seeds = [BID.as_bytes(), auction.key().as_ref(), bidder.key().as_ref()],
+        bump,
+        close = bidder
+    )]
+    bid: Box<Account<'info, Bid>>,
+
+    /// CHECK: global vault pda which stores SOL
+    #[account(
+        seeds = [GLOBAL.as_bytes()],
+        bump,
+    )]
+    pub global_vault: AccountInfo<'info>,
+
+    /// CHECK: ata of global vault
+    #[account(
+        mut,
+        seeds = [
+            global_vault.key().as_ref(),
+            anchor_spl::token::spl_token::ID.as_ref(),
+            token_mint.key().as_ref(),
+        ],
+        bump = bonding_curve.load()?.global_ata_bump,
+        seeds::program = anchor_spl::associated_token::ID
+    )]
+    global_ata: AccountInfo<'info>,
+
+    #[account(
+        mut,
+        token::mint = token_mint,
+        token::authority = bidder
+    )]
+    bidder_token_account: Box<Account<'info, TokenAccount>>,
+
+    #[account(mut)]
+    bidder: Signer<'info>,
+
+    #[account(
+        init_if_needed,
+        payer = bidder,
+        space = 8 + TraderRecord::INIT_SPACE,
+        seeds = [TRADER_RECORD.as_bytes(), bonding_curve.key().as_ref(), bidder.key().as_ref()],
+        bump
+    )]
+    trader_record: Box<Account<'info, TraderRecord>>,
+
+    #[account(address = token::ID)]
+    token_program: Program<'info, Token>,
+
+    system_program: Program<'info, System>,
+}
+
+impl<'info> ClaimAuction<'info> {
+    pub fn process(&mut self, global_vault_bump: u8) -> Result<()> {
+        let bid = &mut self.bid;
+        let auction = &self.auction;
+
+        require!(!bid.is_claimed, PumpfunError::BidAlreadyClaimed);
+
+        let (token_amount, refund_amount) = if auction.is_settled {
+            auction.allocation(bid.amount)?
+        } else {
+            require!(
+                self.bonding_curve.load()?.is_expired(Clock::get()?.unix_timestamp),
+                PumpfunError::AuctionNotSettled
+            );
+            (0, bid.amount)
+        };
+
+        bid.is_claimed = true;
+
+        let trader_record = &mut self.trader_record;
+        trader_record.bonding_curve = self.bonding_curve.key();
+        trader_record.user = self.bidder.key();
+        trader_record.record_trade(Direction::Buy, 0, token_amount);
+
+        if token_amount > 0 {
+            let signer_seeds: &[&[&[u8]]] = &[&[GLOBAL.as_bytes(), &[global_vault_bump]]];
+            token_transfer_with_signer(
+                self.global_ata.clone(),
+                self.global_vault.clone(),
+                self.bidder_token_account.to_account_info(),
+                &self.token_program,
+                signer_seeds,
+                token_amount,
+            )?;
+        }
+
+        if refund_amount > 0 {
+            sol_transfer_from_program_account(
+                auction.to_account_info(),
+                self.bidder.to_account_info(),
+                refund_amount,
+            )?;
+        }
+
+        emit!(AuctionClaimed {
+            auction: auction.key(),
+            bidder: self.bidder.key(),
+            token_amount,
+            refund_amount,
+        });
+
+        Ok(())
+    }
+}
@@ -0,0 +1,51 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::Mint;
+use crate::{
+    constants::{BONDING_CURVE, TRADER_RECORD},
+    errors::*,
+    state::{BondingCurve, TraderRecord},
+};
+
+//  Returns a trader record's rent to the trader once its curve stops trading, callable by anyone
+//  on an expired curve only the trader can close it while it still backs a refund
+#[derive(Accounts)]
+pub struct CloseTraderRecord<'info> {
+    pub token_mint: Box<Account<'info, Mint>>,
+
+    #[account(
+        seeds = [BONDING_CURVE.as_bytes(), &token_mint.key().to_bytes()],
+        bump = bonding_curve.load()?.bump
+    )]
+    bonding_curve: AccountLoader<'info, BondingCurve>,
+
+    #[account(
+        mut,
+        seeds = [TRADER_RECORD.as_bytes(), bonding_curve.key().as_ref(), user.key().as_ref()],
+        bump,
+        close = user
+    )]
+    trader_record: Box<Account<'info, TraderRecord>>,
+
+    /// CHECK: the trader, receives the record's rent
+    #[account(mut)]
+    pub user: AccountInfo<'info>,
+}
+
+impl<'info> CloseTraderRecord<'info> {
+    pub fn process(&mut self) -> Result<()> {
+        let now = Clock::get()?.unix_timestamp;
+        let bonding_curve = self.bonding_curve.load()?;
+
+        //  no more trades can count the trader again
+        require!(
+            bonding_curve.is_completed() || bonding_curve.is_expired(now),
+            PumpfunError::CurveStillTrading
+        );
+        require!(
+            bonding_curve.is_completed() || self.trader_record.bought_token == 0 || self.user.is_signer,
+            PumpfunError::RefundPending
+        );
+
+        Ok(())
+    }
+}
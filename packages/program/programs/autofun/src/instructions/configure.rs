@@ -1,7 +1,7 @@
 use crate::{
-    constants::{CONFIG, GLOBAL},
+    constants::{CONFIG, FEE_CONFIG, FEE_VAULT, GLOBAL},
     errors::*,
-    state::{Config, AmountConfig}, 
+    state::{Config, AmountConfig, FeeConfig, FeeVault, FEE_BASIS_POINTS}, 
     utils::sol_transfer_from_user,
 };
 use anchor_lang::{prelude::*, system_program, Discriminator};
@@ -45,6 +45,26 @@ pub struct Configure<'info> {
     )]
     native_mint: Box<Account<'info, Mint>>,
 
+    //  accrues the fees of SOL curves
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = 8 + FeeVault::INIT_SPACE,
+        seeds = [FEE_VAULT.as_bytes(), Pubkey::default().as_ref()],
+        bump
+    )]
+    fee_vault: Box<Account<'info, FeeVault>>,
+
+    //  swap fees read by the swap instructions
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = 8 + FeeConfig::SIZE,
+        seeds = [FEE_CONFIG.as_bytes()],
+        bump
+    )]
+    fee_config: AccountLoader<'info, FeeConfig>,
+
     #[account(address = system_program::ID)]
     system_program: Program<'info, System>,
 
@@ -54,7 +74,17 @@ pub struct Configure<'info> {
 }
 
 impl<'info> Configure<'info> {
-    pub fn process(&mut self, new_config: Config, config_bump: u8) -> Result<()> {
+    #[allow(clippy::too_many_arguments)]
+    pub fn process(
+        &mut self,
+        new_config: Config,
+        platform_buy_fee: u64,
+        platform_sell_fee: u64,
+        config_bump: u8,
+        fee_config_bump: u8,
+        fee_vault_bump: u8,
+        global_vault_bump: u8,
+    ) -> Result<()> {
         let serialized_config =
             [&Config::DISCRIMINATOR, new_config.try_to_vec()?.as_slice()].concat();
         let serialized_config_len = serialized_config.len();
@@ -75,7 +105,17 @@ impl<'info> Configure<'info> {
                 }
             }
         }
+
+        if platform_buy_fee as u128 > FEE_BASIS_POINTS
+            || platform_sell_fee as u128 > FEE_BASIS_POINTS
+        {
+            return err!(PumpfunError::ValueTooLarge);
+        }
     
+        //  fee_config is created alongside every config with the current layout,
+        //  an existing config without one still has the legacy layout
+        let fee_config_is_new = self.fee_config.load().is_err();
+
         // Init config pda if needed
         if self.config.owner != &crate::ID {
             let cpi_context = CpiContext::new(
@@ -93,6 +133,10 @@ impl<'info> Configure<'info> {
                 &crate::ID,
             )?;
         } else {
+            if fee_config_is_new {
+                return err!(PumpfunError::ConfigNeedsMigration);
+            }
+
             let data = self.config.try_borrow_data()?;
             if data.len() < 8 || data[0..8] != Config::DISCRIMINATOR {
                 return err!(PumpfunError::IncorrectConfigAccount);
@@ -131,6 +175,19 @@ impl<'info> Configure<'info> {
         (self.config.try_borrow_mut_data()?[..serialized_config_len])
             .copy_from_slice(serialized_config.as_slice());
     
+        //  swap fees live in their own fixed size account
+        let fee_config = &mut if fee_config_is_new {
+            self.fee_config.load_init()?
+        } else {
+            self.fee_config.load_mut()?
+        };
+        fee_config.platform_buy_fee = platform_buy_fee;
+        fee_config.platform_sell_fee = platform_sell_fee;
+        fee_config.bump = fee_config_bump;
+        fee_config.global_vault_bump = global_vault_bump;
+
+        self.fee_vault.bump = fee_vault_bump;
+
         // Initialize global vault if needed
         if self.global_vault.lamports() == 0 {
             sol_transfer_from_user(
@@ -0,0 +1,143 @@
+use crate::{
+    constants::{CONFIG, FEE_VAULT, GLOBAL, QUOTE_CONFIG},
+    errors::*,
+    state::{Config, FeeVault, QuoteConfig},
+};
+use anchor_lang::{prelude::*, system_program, Discriminator};
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token::{Mint, Token, TokenAccount},
+};
+
+#[derive(Accounts)]
+pub struct ConfigureQuote<'info> {
+    #[account(
+        mut,
+        constraint = global_config.authority == admin.key() @PumpfunError::IncorrectAuthority
+    )]
+    admin: Signer<'info>,
+
+    #[account(
+        seeds = [CONFIG.as_bytes()],
+        bump,
+    )]
+    global_config: Box<Account<'info, Config>>,
+
+    /// CHECK: initialization handled inside the instruction
+    #[account(
+        mut,
+        seeds = [QUOTE_CONFIG.as_bytes(), quote_mint.key().as_ref()],
+        bump,
+    )]
+    quote_config: AccountInfo<'info>,
+
+    /// CHECK: global vault pda which owns the quote vault
+    #[account(
+        seeds = [GLOBAL.as_bytes()],
+        bump,
+    )]
+    pub global_vault: AccountInfo<'info>,
+
+    quote_mint: Box<Account<'info, Mint>>,
+
+    //  holds the quote reserves of every curve launched against this mint
+    #[account(
+        init_if_needed,
+        payer = admin,
+        associated_token::mint = quote_mint,
+        associated_token::authority = global_vault
+    )]
+    quote_vault: Box<Account<'info, TokenAccount>>,
+
+    //  accrues the fees of curves in this quote mint
+    #[account(
+        init_if_needed,
+        payer = admin,
+        space = 8 + FeeVault::INIT_SPACE,
+        seeds = [FEE_VAULT.as_bytes(), quote_mint.key().as_ref()],
+        bump
+    )]
+    fee_vault: Box<Account<'info, FeeVault>>,
+
+    #[account(address = system_program::ID)]
+    system_program: Program<'info, System>,
+
+    token_program: Program<'info, Token>,
+
+    associated_token_program: Program<'info, AssociatedToken>,
+}
+
+impl<'info> ConfigureQuote<'info> {
+    pub fn process(
+        &mut self,
+        new_quote_config: QuoteConfig,
+        quote_config_bump: u8,
+        fee_vault_bump: u8,
+    ) -> Result<()> {
+        //  SOL curves are priced in lamports, not wrapped SOL
+        require!(
+            new_quote_config.quote_mint == self.quote_mint.key()
+                && new_quote_config.quote_mint != spl_token::native_mint::ID,
+            PumpfunError::IncorrectQuoteMint
+        );
+
+        let serialized_config =
+            [&QuoteConfig::DISCRIMINATOR, new_quote_config.try_to_vec()?.as_slice()].concat();
+        let serialized_config_len = serialized_config.len();
+        let config_cost = Rent::get()?.minimum_balance(serialized_config_len);
+
+        // Init quote config pda if needed
+        if self.quote_config.owner != &crate::ID {
+            let quote_mint_key = self.quote_mint.key();
+            let cpi_context = CpiContext::new(
+                self.system_program.to_account_info(),
+                system_program::CreateAccount {
+                    from: self.admin.to_account_info(),
+                    to: self.quote_config.to_account_info(),
+                },
+            );
+
+            system_program::create_account(
+                cpi_context.with_signer(&[&[
+                    QUOTE_CONFIG.as_bytes(),
+                    quote_mint_key.as_ref(),
+                    &[quote_config_bump],
+                ]]),
+                config_cost,
+                serialized_config_len as u64,
+                &crate::ID,
+            )?;
+        } else {
+            let data = self.quote_config.try_borrow_data()?;
+            if data.len() < 8 || data[0..8] != QuoteConfig::DISCRIMINATOR {
+                return err!(PumpfunError::IncorrectConfigAccount);
+            }
+        }
+
+        let lamport_delta = (config_cost as i64) - (self.quote_config.lamports() as i64);
+        if lamport_delta > 0 {
+            system_program::transfer(
+                CpiContext::new(
+                    self.system_program.to_account_info(),
+                    system_program::Transfer {
+                        from: self.admin.to_account_info(),
+                        to: self.quote_config.to_account_info(),
+                    },
+                ),
+                lamport_delta as u64,
+            )?;
+        }
+
+        if serialized_config_len > self.quote_config.data_len() {
+            self.quote_config.realloc(serialized_config_len, false)?;
+        }
+
+        (self.quote_config.try_borrow_mut_data()?[..serialized_config_len])
+            .copy_from_slice(serialized_config.as_slice());
+
+        self.fee_vault.quote_mint = self.quote_mint.key();
+        self.fee_vault.bump = fee_vault_bump;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,252 @@
+use crate::{
+    constants::{BONDING_CURVE, FEE_CONFIG, FEE_VAULT, GLOBAL, ORDER, TRADER_RECORD},
+    errors::*,
+    events::OrderFilled,
+    state::{BondingCurve, BondingCurveAccount, Direction, FeeConfig, FeeVault, Order, SwapAmounts, TraderRecord},
+    utils::{sol_transfer_from_program_account, sol_transfer_with_signer, token_transfer_with_signer},
+};
+use anchor_lang::{prelude::*, system_program};
+use anchor_spl::token::{self, Mint, Token, TokenAccount};
+
+//  Executes a limit order once the curve price crosses its limit, callable by anyone
+//  the keeper is paid the order's tip, and the order is closed to its owner
+#[derive(Accounts)]
+pub struct FillOrder<'info> {
+    #[account(
+        seeds = [FEE_CONFIG.as_bytes()],
+        bump = fee_config.load()?.bump,
+    )]
+    fee_config: AccountLoader<'info, FeeConfig>,
+
+    #[account(
+        mut,
+        seeds = [BONDING_CURVE.as_bytes(), &token_mint.key().to_bytes()],
+        bump = bonding_curve.load()?.bump
+    )]
+    bonding_curve: AccountLoader<'info, BondingCurve>,
+
+    //  accrues the swap fee, swept to the team wallet by sweep_fees
+    #[account(
+        mut,
+        seeds = [FEE_VAULT.as_bytes(), bonding_curve.load()?.quote_mint.as_ref()],
+        bump = fee_vault.bump
+    )]
+    fee_vault: Box<Account<'info, FeeVault>>,
+
+    //  the order's owner is the trader, created by place_order so the keeper never pays its rent
+    #[account(
+        mut,
+        seeds = [TRADER_RECORD.as_bytes(), bonding_curve.key().as_ref(), owner.key().as_ref()],
+        bump
+    )]
+    trader_record: Box<Account<'info, TraderRecord>>,
+
+    /// CHECK: global vault pda which stores SOL
+    #[account(
+        mut,
+        seeds = [GLOBAL.as_bytes()],
+        bump = fee_config.load()?.global_vault_bump,
+    )]
+    pub global_vault: AccountInfo<'info>,
+
+    pub token_mint: Box<Account<'info, Mint>>,
+
+    /// CHECK: ata of global vault
+    #[account(
+        mut,
+        seeds = [
+            global_vault.key().as_ref(),
+            anchor_spl::token::spl_token::ID.as_ref(),
+            token_mint.key().as_ref(),
+        ],
+        bump = bonding_curve.load()?.global_ata_bump,
+        seeds::program = anchor_spl::associated_token::ID
+    )]
+    global_ata: AccountInfo<'info>,
+
+    #[account(
+        mut,
+        seeds = [ORDER.as_bytes(), bonding_curve.key().as_ref(), owner.key().as_ref(), &order.nonce.to_le_bytes()],
+        bump = order.bump,
+        has_one = owner,
+        close = owner
+    )]
+    order: Box<Account<'info, Order>>,
+
+    //  holds the tokens of a sell order, buy orders have none
+    #[account(
+        mut,
+        associated_token::mint = token_mint,
+        associated_token::authority = order
+    )]
+    order_token_account: Option<Box<Account<'info, TokenAccount>>>,
+
+    //  receives the tokens of a buy order, and any tokens left in a sell order's escrow
+    #[account(
+        mut,
+        token::mint = token_mint,
+        token::authority = owner
+    )]
+    owner_token_account: Box<Account<'info, TokenAccount>>,
+
+    /// CHECK: owner of the order, receives the SOL of a sell order and the closed accounts' rent
+    #[account(mut)]
+    pub owner: AccountInfo<'info>,
+
+    #[account(mut)]
+    pub keeper: Signer<'info>,
+
+    #[account(address = system_program::ID)]
+    pub system_program: Program<'info, System>,
+
+    #[account(address = token::ID)]
+    pub token_program: Program<'info, Token>,
+}
+
+impl<'info> FillOrder<'info> {
+    pub fn process(&mut self) -> Result<()> {
+        let now = Clock::get()?.unix_timestamp;
+        let order = &self.order;
+
+        require!(now < order.expires_at, PumpfunError::OrderExpired);
+
+        let bonding_curve_key = self.bonding_curve.key();
+        let mut bonding_curve = self.bonding_curve.load_mut()?;
+        let fee_config = self.fee_config.load()?;
+
+        //  check curve is not completed
+        require!(
+            !bonding_curve.is_completed(),
+            PumpfunError::CurveAlreadyCompleted
+        );
+
+        //  check trading has started for scheduled launches
+        require!(
+            now >= bonding_curve.trading_starts_at,
+            PumpfunError::TradingNotStarted
+        );
+
+        require!(
+            order.is_triggered(bonding_curve.spot_price()),
+            PumpfunError::OrderNotTriggered
+        );
+
+        //  the minimum receive amount keeps the fill at or better than the limit price, fee included
+        let SwapAmounts { amount_in, amount_out, fee: _ } = bonding_curve.apply_swap(
+            &bonding_curve_key,
+            &fee_config,
+            &self.token_mint,
+            &mut self.fee_vault,
+            &mut self.trader_record,
+            order.amount,
+            order.direction,
+            order.minimum_receive_amount()?,
+            now,
+            &order.owner,
+        )?;
+        bonding_curve.log_completion();
+
+        let signer_seeds: &[&[&[u8]]] = &[&[
+            GLOBAL.as_bytes(),
+            &[fee_config.global_vault_bump],
+        ]];
+        let nonce = order.nonce.to_le_bytes();
+        let order_seeds: &[&[&[u8]]] = &[&[
+            ORDER.as_bytes(),
+            bonding_curve_key.as_ref(),
+            order.owner.as_ref(),
+            &nonce,
+            &[order.bump],
+        ]];
+
+        match order.direction {
+            Direction::Sell => {
+                let Some(order_token_account) = &self.order_token_account else {
+                    return err!(PumpfunError::MissingOrderTokenAccount);
+                };
+
+                // Transfer escrowed tokens from order to pool
+                token_transfer_with_signer(
+                    order_token_account.to_account_info(),
+                    order.to_account_info(),
+                    self.global_ata.clone(),
+                    &self.token_program,
+                    order_seeds,
+                    amount_in,
+                )?;
+
+                // Transfer net SOL from pool to owner
+                sol_transfer_with_signer(
+                    self.global_vault.clone(),
+                    self.owner.clone(),
+                    &self.system_program,
+                    signer_seeds,
+                    amount_out,
+                )?;
+
+                //  tokens sent to the escrow beyond the order go back to the owner, as in cancel_order,
+                //  so a dust transfer can't keep the account from closing
+                let leftover = order_token_account
+                    .amount
+                    .checked_sub(amount_in)
+                    .ok_or(PumpfunError::OverflowOrUnderflowOccurred)?;
+                if leftover > 0 {
+                    token_transfer_with_signer(
+                        order_token_account.to_account_info(),
+                        order.to_account_info(),
+                        self.owner_token_account.to_account_info(),
+                        &self.token_program,
+                        order_seeds,
+                        leftover,
+                    )?;
+                }
+
+                token::close_account(CpiContext::new_with_signer(
+                    self.token_program.to_account_info(),
+                    token::CloseAccount {
+                        account: order_token_account.to_account_info(),
+                        destination: self.owner.clone(),
+                        authority: order.to_account_info(),
+                    },
+                    order_seeds,
+                ))?;
+            }
+            Direction::Buy => {
+                // Transfer tokens from pool to owner
+                token_transfer_with_signer(
+                    self.global_ata.clone(),
+                    self.global_vault.clone(),
+                    self.owner_token_account.to_account_info(),
+                    &self.token_program,
+                    signer_seeds,
+                    amount_out,
+                )?;
+
+                // Transfer escrowed SOL from order to pool, fee included
+                // SOL left above the curve limit returns to the owner when the order closes
+                sol_transfer_from_program_account(
+                    order.to_account_info(),
+                    self.global_vault.clone(),
+                    amount_in,
+                )?;
+            }
+        }
+
+        sol_transfer_from_program_account(
+            order.to_account_info(),
+            self.keeper.to_account_info(),
+            order.keeper_tip,
+        )?;
+
+        emit!(OrderFilled {
+            order: order.key(),
+            owner: order.owner,
+            keeper: self.keeper.key(),
+            amount_in,
+            amount_out,
+            keeper_tip: order.keeper_tip,
+        });
+
+        Ok(())
+    }
+}
@@ -0,0 +1,25 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::Mint;
+use crate::{
+    constants::BONDING_CURVE,
+    state::BondingCurve,
+};
+
+//  Read only, the price is returned through return data
+#[derive(Accounts)]
+pub struct GetTwap<'info> {
+    pub token_mint: Box<Account<'info, Mint>>,
+
+    #[account(
+        seeds = [BONDING_CURVE.as_bytes(), &token_mint.key().to_bytes()], 
+        bump = bonding_curve.load()?.bump
+    )]
+    bonding_curve: AccountLoader<'info, BondingCurve>,
+}
+
+impl<'info> GetTwap<'info> {
+    pub fn process(&self, window: u32) -> Result<u64> {
+        let now = Clock::get()?.unix_timestamp;
+        self.bonding_curve.load()?.twap(now, window)
+    }
+}
@@ -3,7 +3,7 @@ use std::ops::{Div, Mul};
 use crate::{
     constants::{BONDING_CURVE, CONFIG, GLOBAL, METADATA},
     errors::*,
-    state::{BondingCurve, Config},
+    state::{BondingCurve, Config, QuoteConfig},
 };
 use anchor_lang::{prelude::*, solana_program::sysvar::SysvarId, system_program};
 use anchor_spl::{
@@ -12,8 +12,21 @@ use anchor_spl::{
     token::{self, spl_token::instruction::AuthorityType, Mint, Token},
 };
 
+//  launch config and metadata, shared by every launch instruction
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct LaunchParams {
+    pub decimals: u8,
+    pub token_supply: u64,
+    pub virtual_lamport_reserves: u64,
+    pub name: String,
+    pub symbol: String,
+    pub uri: String,
+    pub trading_starts_at: Option<i64>, //  scheduled start of trading, trades immediately if None
+    pub expires_at: Option<i64>, //  unfinished curves stop trading and can be refunded after this
+}
+
 #[derive(Accounts)]
-#[instruction(decimals: u8)]
+#[instruction(params: LaunchParams)]
 pub struct Launch<'info> {
     #[account(
         mut,
@@ -36,7 +49,7 @@ pub struct Launch<'info> {
     #[account(
         init,
         payer = creator,
-        mint::decimals = decimals,
+        mint::decimals = params.decimals,
         mint::authority = global_vault.key(),
     )]
     token: Box<Account<'info, Mint>>,
@@ -44,11 +57,11 @@ pub struct Launch<'info> {
     #[account(
         init,
         payer = creator,
-        space = 8 + BondingCurve::INIT_SPACE,
+        space = 8 + BondingCurve::SIZE,
         seeds = [BONDING_CURVE.as_bytes(), &token.key().to_bytes()],
         bump
     )]
-    bonding_curve: Box<Account<'info, BondingCurve>>,
+    bonding_curve: AccountLoader<'info, BondingCurve>,
 
     /// CHECK: passed to token metadata program
     #[account(
@@ -111,173 +124,285 @@ pub struct Launch<'info> {
         seeds::program = anchor_spl::associated_token::ID
     )]
     team_wallet_ata: AccountInfo<'info>,
+
+    //  config of the SPL quote mint, omit to launch a SOL curve
+    quote_config: Option<Box<Account<'info, QuoteConfig>>>,
 }
-#[allow(clippy::too_many_arguments)]
 impl<'info> Launch<'info> {
-    pub fn process(
-        &mut self,
-
-        // launch config
-        decimals: u8,
-        token_supply: u64,
-        reserve_lamport: u64,
-
-        // metadata
-        name: String,
-        symbol: String,
-        uri: String,
-
-        global_vault_bump: u8,
-    ) -> Result<()> {
-        let global_config = &self.global_config;
-        let creator = &self.creator;
-        let token = &self.token;
-        let global_token_account = &self.global_token_account;
-        let bonding_curve = &mut self.bonding_curve;
-        let global_vault = &self.global_vault;
-        let team_wallet = &mut self.team_wallet;
-        let team_wallet_ata = &self.team_wallet_ata;
-
-        // Decimal overflow check
-        if decimals >= 20 {
-            return err!(PumpfunError::DecimalOverflow);
-        }
-
-        // Check if token supply is a whole number of tokens
-        let decimal_multiplier = 10u64.pow(decimals as u32);
-        let fractional_tokens = token_supply % decimal_multiplier;
-        if fractional_tokens != 0 {
-            msg!("expected whole number of tokens, got fractional tokens: 0.{fractional_tokens}");
-            return Err(ValueInvalid.into());
-        }
-
-        global_config
-            .lamport_amount_config
-            .validate(&reserve_lamport)?;
-
-        global_config
-            .token_supply_config
-            .validate(&(token_supply / decimal_multiplier))?;
+    pub fn process(&mut self, params: LaunchParams, bumps: &LaunchBumps) -> Result<()> {
+        launch_token(
+            LaunchAccounts {
+                global_config: &self.global_config,
+                global_vault: &self.global_vault,
+                creator: &self.creator,
+                token: &self.token,
+                bonding_curve: &mut *self.bonding_curve.load_init()?,
+                token_metadata_account: &self.token_metadata_account,
+                global_token_account: &self.global_token_account,
+                team_wallet: &self.team_wallet,
+                team_wallet_ata: &self.team_wallet_ata,
+                system_program: &self.system_program,
+                rent: &self.rent,
+                token_program: &self.token_program,
+                associated_token_program: &self.associated_token_program,
+                mpl_token_metadata_program: &self.mpl_token_metadata_program,
+                quote_config: self.quote_config.as_deref().map(|quote_config| &**quote_config),
+            },
+            params,
+            CurveBumps {
+                global_vault: bumps.global_vault,
+                bonding_curve: bumps.bonding_curve,
+                global_token_account: bumps.global_token_account,
+            },
+        )
+    }
+}
 
-        global_config.token_decimals_config.validate(&decimals)?;
+//  accounts shared by every launch path, however the mint was created
+pub struct LaunchAccounts<'a, 'info> {
+    pub global_config: &'a Config,
+    pub global_vault: &'a AccountInfo<'info>,
+    pub creator: &'a Signer<'info>,
+    pub token: &'a Account<'info, Mint>,
+    pub bonding_curve: &'a mut BondingCurve,
+    pub token_metadata_account: &'a AccountInfo<'info>,
+    pub global_token_account: &'a AccountInfo<'info>,
+    pub team_wallet: &'a AccountInfo<'info>,
+    pub team_wallet_ata: &'a AccountInfo<'info>,
+    pub system_program: &'a Program<'info, System>,
+    pub rent: &'a Sysvar<'info, Rent>,
+    pub token_program: &'a Program<'info, Token>,
+    pub associated_token_program: &'a Program<'info, AssociatedToken>,
+    pub mpl_token_metadata_program: &'a Program<'info, Metadata>,
+    pub quote_config: Option<&'a QuoteConfig>,
+}
 
-        let init_bonding_curve = (token_supply as f64)
-            .mul(global_config.init_bonding_curve)
-            .div(100_f64) as u64;
+//  pda bumps stored on the bonding curve so swaps don't re-derive them
+pub struct CurveBumps {
+    pub global_vault: u8,
+    pub bonding_curve: u8,
+    pub global_token_account: u8,
+}
 
-        let amount_to_team = token_supply - init_bonding_curve;
+//  validates the launch config, initializes the bonding curve,
+//  mints the supply, creates metadata and revokes mint authority
+pub fn launch_token(
+    accounts: LaunchAccounts<'_, '_>,
+    params: LaunchParams,
+    bumps: CurveBumps,
+) -> Result<()> {
+    let LaunchAccounts {
+        global_config,
+        global_vault,
+        creator,
+        token,
+        bonding_curve,
+        token_metadata_account,
+        global_token_account,
+        team_wallet,
+        team_wallet_ata,
+        system_program,
+        rent,
+        token_program,
+        associated_token_program,
+        mpl_token_metadata_program,
+        quote_config,
+    } = accounts;
+    let LaunchParams {
+        decimals,
+        token_supply,
+        virtual_lamport_reserves: reserve_lamport,
+        name,
+        symbol,
+        uri,
+        trading_starts_at,
+        expires_at,
+    } = params;
+
+    // Decimal overflow check
+    if decimals >= 20 {
+        return err!(PumpfunError::DecimalOverflow);
+    }
 
-        // create token launch pda
-        bonding_curve.token_mint = token.key();
-        bonding_curve.creator = creator.key();
-        bonding_curve.init_lamport = reserve_lamport;
-        bonding_curve.reserve_lamport = reserve_lamport;
-        bonding_curve.reserve_token = init_bonding_curve;
-        bonding_curve.curve_limit = global_config.curve_limit;
+    // Check if token supply is a whole number of tokens
+    let decimal_multiplier = 10u64.pow(decimals as u32);
+    let fractional_tokens = token_supply % decimal_multiplier;
+    if fractional_tokens != 0 {
+        msg!("expected whole number of tokens, got fractional tokens: 0.{fractional_tokens}");
+        return Err(ValueInvalid.into());
+    }
 
-        // create global token account
-        associated_token::create(CpiContext::new(
-            self.associated_token_program.to_account_info(),
-            associated_token::Create {
-                payer: creator.to_account_info(),
-                associated_token: global_token_account.to_account_info(),
+    //  curves quoted in an SPL mint take their limits from its quote config
+    let (curve_limit, reserve_amount_config, quote_mint) = match quote_config {
+        Some(quote_config) => {
+            require!(quote_config.enabled, PumpfunError::QuoteMintDisabled);
+            (
+                quote_config.curve_limit,
+                &quote_config.reserve_amount_config,
+                quote_config.quote_mint,
+            )
+        }
+        None => (
+            global_config.curve_limit,
+            &global_config.lamport_amount_config,
+            Pubkey::default(),
+        ),
+    };
+
+    reserve_amount_config.validate(&reserve_lamport)?;
+
+    global_config
+        .token_supply_config
+        .validate(&(token_supply / decimal_multiplier))?;
+
+    global_config.token_decimals_config.validate(&decimals)?;
+
+    let trading_starts_at = match trading_starts_at {
+        Some(starts_at) => {
+            require!(
+                starts_at > Clock::get()?.unix_timestamp,
+                PumpfunError::InvalidTradingStartTime
+            );
+            starts_at
+        }
+        None => 0,
+    };
+
+    let expires_at = match expires_at {
+        Some(expires_at) => {
+            require!(
+                expires_at > Clock::get()?.unix_timestamp && expires_at > trading_starts_at,
+                PumpfunError::InvalidExpiry
+            );
+            expires_at
+        }
+        None => 0,
+    };
+
+    let init_bonding_curve = (token_supply as f64)
+        .mul(global_config.init_bonding_curve)
+        .div(100_f64) as u64;
+
+    let amount_to_team = token_supply - init_bonding_curve;
+
+    // create token launch pda
+    bonding_curve.token_mint = token.key();
+    bonding_curve.creator = creator.key();
+    bonding_curve.init_lamport = reserve_lamport;
+    bonding_curve.reserve_lamport = reserve_lamport;
+    bonding_curve.reserve_token = init_bonding_curve;
+    bonding_curve.init_reserve_token = init_bonding_curve;
+    bonding_curve.curve_limit = curve_limit;
+    bonding_curve.trading_starts_at = trading_starts_at;
+    bonding_curve.expires_at = expires_at;
+    bonding_curve.quote_mint = quote_mint;
+    bonding_curve.bump = bumps.bonding_curve;
+    bonding_curve.global_ata_bump = bumps.global_token_account;
+    //  first twap observation at the launch price
+    bonding_curve.record_price(Clock::get()?.unix_timestamp);
+
+    // create global token account
+    associated_token::create(CpiContext::new(
+        associated_token_program.to_account_info(),
+        associated_token::Create {
+            payer: creator.to_account_info(),
+            associated_token: global_token_account.to_account_info(),
+            authority: global_vault.to_account_info(),
+            mint: token.to_account_info(),
+            token_program: token_program.to_account_info(),
+            system_program: system_program.to_account_info(),
+        },
+    ))?;
+    // create team token account
+    anchor_spl::associated_token::create(CpiContext::new(
+        associated_token_program.to_account_info(),
+        anchor_spl::associated_token::Create {
+            payer: creator.to_account_info(),
+            associated_token: team_wallet_ata.to_account_info(),
+            authority: team_wallet.to_account_info(),
+
+            mint: token.to_account_info(),
+            system_program: system_program.to_account_info(),
+            token_program: token_program.to_account_info(),
+        },
+    ))?;
+    let signer_seeds: &[&[&[u8]]] = &[&[GLOBAL.as_bytes(), &[bumps.global_vault]]];
+
+    // mint tokens to bonding curve & team
+    token::mint_to(
+        CpiContext::new_with_signer(
+            token_program.to_account_info(),
+            token::MintTo {
+                mint: token.to_account_info(),
+                to: global_token_account.to_account_info(),
                 authority: global_vault.to_account_info(),
+            },
+            signer_seeds,
+        ),
+        init_bonding_curve,
+    )?;
+    token::mint_to(
+        CpiContext::new_with_signer(
+            token_program.to_account_info(),
+            token::MintTo {
                 mint: token.to_account_info(),
-                token_program: self.token_program.to_account_info(),
-                system_program: self.system_program.to_account_info(),
+                to: team_wallet_ata.to_account_info(),
+                authority: global_vault.to_account_info(),
             },
-        ))?;
-        // create team token account
-        anchor_spl::associated_token::create(CpiContext::new(
-            self.associated_token_program.to_account_info(),
-            anchor_spl::associated_token::Create {
-                payer: creator.to_account_info(),
-                associated_token: team_wallet_ata.to_account_info(),
-                authority: team_wallet.to_account_info(),
-
+            signer_seeds,
+        ),
+        amount_to_team,
+    )?;        
+
+    // create metadata
+    metadata::create_metadata_accounts_v3(
+        CpiContext::new_with_signer(
+            mpl_token_metadata_program.to_account_info(),
+            metadata::CreateMetadataAccountsV3 {
+                metadata: token_metadata_account.to_account_info(),
                 mint: token.to_account_info(),
-                system_program: self.system_program.to_account_info(),
-                token_program: self.token_program.to_account_info(),
+                mint_authority: global_vault.to_account_info(),
+                payer: creator.to_account_info(),
+                update_authority: global_vault.to_account_info(),
+                system_program: system_program.to_account_info(),
+                rent: rent.to_account_info(),
             },
-        ))?;
-        let signer_seeds: &[&[&[u8]]] = &[&[GLOBAL.as_bytes(), &[global_vault_bump]]];
-
-        // mint tokens to bonding curve & team
-        token::mint_to(
-            CpiContext::new_with_signer(
-                self.token_program.to_account_info(),
-                token::MintTo {
-                    mint: token.to_account_info(),
-                    to: global_token_account.to_account_info(),
-                    authority: global_vault.to_account_info(),
-                },
-                signer_seeds,
-            ),
-            init_bonding_curve,
-        )?;
-        token::mint_to(
-            CpiContext::new_with_signer(
-                self.token_program.to_account_info(),
-                token::MintTo {
-                    mint: token.to_account_info(),
-                    to: team_wallet_ata.to_account_info(),
-                    authority: global_vault.to_account_info(),
-                },
-                signer_seeds,
-            ),
-            amount_to_team,
-        )?;        
-
-        // create metadata
-        metadata::create_metadata_accounts_v3(
-            CpiContext::new_with_signer(
-                self.mpl_token_metadata_program.to_account_info(),
-                metadata::CreateMetadataAccountsV3 {
-                    metadata: self.token_metadata_account.to_account_info(),
-                    mint: token.to_account_info(),
-                    mint_authority: global_vault.to_account_info(),
-                    payer: creator.to_account_info(),
-                    update_authority: global_vault.to_account_info(),
-                    system_program: self.system_program.to_account_info(),
-                    rent: self.rent.to_account_info(),
-                },
-                signer_seeds,
-            ),
-            DataV2 {
-                name,
-                symbol,
-                uri,
-                seller_fee_basis_points: 0,
-                creators: None,
-                collection: None,
-                uses: None,
+            signer_seeds,
+        ),
+        DataV2 {
+            name,
+            symbol,
+            uri,
+            seller_fee_basis_points: 0,
+            creators: None,
+            collection: None,
+            uses: None,
+        },
+        false,
+        true,
+        None,
+    )?;
+
+    //  revoke mint authority
+    token::set_authority(
+        CpiContext::new_with_signer(
+            token_program.to_account_info(),
+            token::SetAuthority {
+                current_authority: global_vault.to_account_info(),
+                account_or_mint: token.to_account_info(),
             },
-            false,
-            true,
-            None,
-        )?;
-
-        //  revoke mint authority
-        token::set_authority(
-            CpiContext::new_with_signer(
-                self.token_program.to_account_info(),
-                token::SetAuthority {
-                    current_authority: global_vault.to_account_info(),
-                    account_or_mint: token.to_account_info(),
-                },
-                signer_seeds,
-            ),
-            AuthorityType::MintTokens,
-            None,
-        )?;
-
-        bonding_curve.is_completed = false;
-
-        msg!("NewToken: {} {}", 
-            bonding_curve.token_mint, 
-            bonding_curve.creator
-        );
-
-        Ok(())
-    }
+            signer_seeds,
+        ),
+        AuthorityType::MintTokens,
+        None,
+    )?;
+
+    bonding_curve.is_completed = 0;
+
+    msg!("NewToken: {} {}", 
+        bonding_curve.token_mint, 
+        bonding_curve.creator
+    );
+
+    Ok(())
 }
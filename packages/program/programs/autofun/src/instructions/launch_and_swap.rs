@@ -1,19 +1,18 @@
-use std::ops::{Div, Mul};
-
 use crate::{
-    constants::{BONDING_CURVE, CONFIG, GLOBAL, METADATA},
+    constants::{BONDING_CURVE, CONFIG, FEE_CONFIG, FEE_VAULT, GLOBAL, METADATA, TRADER_RECORD},
     errors::*,
-    state::{BondingCurve, BondingCurveAccount, Config},
+    instructions::launch::{launch_token, CurveBumps, LaunchAccounts, LaunchParams},
+    state::{BondingCurve, BondingCurveAccount, Config, Direction, FeeConfig, FeeVault, TraderRecord},
 };
 use anchor_lang::{prelude::*, solana_program::sysvar::SysvarId, system_program};
 use anchor_spl::{
     associated_token::{self, AssociatedToken},
-    metadata::{self, mpl_token_metadata::types::DataV2, Metadata},
-    token::{self, spl_token::instruction::AuthorityType, Mint, Token},
+    metadata::{self, Metadata},
+    token::{self, Mint, Token},
 };
 
 #[derive(Accounts)]
-#[instruction(decimals: u8)]
+#[instruction(params: LaunchParams)]
 pub struct LaunchAndSwap<'info> {
     #[account(
         mut,
@@ -36,7 +35,7 @@ pub struct LaunchAndSwap<'info> {
     #[account(
         init,
         payer = creator,
-        mint::decimals = decimals,
+        mint::decimals = params.decimals,
         mint::authority = global_vault.key(),
     )]
     token: Box<Account<'info, Mint>>,
@@ -44,11 +43,35 @@ pub struct LaunchAndSwap<'info> {
     #[account(
         init,
         payer = creator,
-        space = 8 + BondingCurve::INIT_SPACE,
+        space = 8 + BondingCurve::SIZE,
         seeds = [BONDING_CURVE.as_bytes(), &token.key().to_bytes()],
         bump
     )]
-    bonding_curve: Box<Account<'info, BondingCurve>>,
+    bonding_curve: AccountLoader<'info, BondingCurve>,
+
+    //  accrues the fee of the initial buy
+    #[account(
+        mut,
+        seeds = [FEE_VAULT.as_bytes(), Pubkey::default().as_ref()],
+        bump
+    )]
+    fee_vault: Box<Account<'info, FeeVault>>,
+
+    //  created on the creator's first trade on this curve, counts unique traders
+    #[account(
+        init_if_needed,
+        payer = creator,
+        space = 8 + TraderRecord::INIT_SPACE,
+        seeds = [TRADER_RECORD.as_bytes(), bonding_curve.key().as_ref(), creator.key().as_ref()],
+        bump
+    )]
+    trader_record: Box<Account<'info, TraderRecord>>,
+
+    #[account(
+        seeds = [FEE_CONFIG.as_bytes()],
+        bump
+    )]
+    fee_config: AccountLoader<'info, FeeConfig>,
 
     /// CHECK: passed to token metadata program
     #[account(
@@ -129,225 +152,92 @@ pub struct LaunchAndSwap<'info> {
 impl<'info> LaunchAndSwap<'info> {
     pub fn process(
         &mut self,
-        // launch config
-        decimals: u8,
-        token_supply: u64,
-        reserve_lamport: u64,
-        // metadata
-        name: String,
-        symbol: String,
-        uri: String,
+        params: LaunchParams,
         // swap config
         swap_amount: u64,
         minimum_receive_amount: u64,
         deadline: i64,
-        global_vault_bump: u8,
+        bumps: &LaunchAndSwapBumps,
     ) -> Result<u64> {
+        //  the curve is created by this instruction, its discriminator is only written on exit
+        //  so launch and swap share the one load_init borrow instead of calling load_mut
+        let bonding_curve_loader = self.bonding_curve.clone();
+        let mut bonding_curve = bonding_curve_loader.load_init()?;
+
         // First, process the launch part
         self.process_launch(
-            decimals,
-            token_supply,
-            reserve_lamport,
-            name,
-            symbol,
-            uri,
-            global_vault_bump,
+            &mut bonding_curve,
+            params,
+            CurveBumps {
+                global_vault: bumps.global_vault,
+                bonding_curve: bumps.bonding_curve,
+                global_token_account: bumps.global_token_account,
+            },
         )?;
 
         // Then, process the swap part
+        // The creator's initial buy is not subject to trading_starts_at
         self.process_swap(
+            &mut bonding_curve,
             swap_amount,
-            0, // direction = 0 for buying tokens with SOL
+            Direction::Buy, // buying tokens with SOL
             minimum_receive_amount,
             deadline,
-            global_vault_bump,
+            bumps.global_vault,
         )
     }
 
     fn process_launch(
         &mut self,
-        // launch config
-        decimals: u8,
-        token_supply: u64,
-        reserve_lamport: u64,
-        // metadata
-        name: String,
-        symbol: String,
-        uri: String,
-        global_vault_bump: u8,
+        bonding_curve: &mut BondingCurve,
+        params: LaunchParams,
+        bumps: CurveBumps,
     ) -> Result<()> {
-        let global_config = &self.global_config;
-        let creator = &self.creator;
-        let token = &self.token;
-        let global_token_account = &self.global_token_account;
-        let bonding_curve = &mut self.bonding_curve;
-        let global_vault = &self.global_vault;
-        let team_wallet = &mut self.team_wallet;
-        let team_wallet_ata = &self.team_wallet_ata;
-
-        // Decimal overflow check
-        if decimals >= 20 {
-            return err!(PumpfunError::DecimalOverflow);
-        }
-
-        // Check if token supply is a whole number of tokens
-        let decimal_multiplier = 10u64.pow(decimals as u32);
-        let fractional_tokens = token_supply % decimal_multiplier;
-        if fractional_tokens != 0 {
-            msg!("expected whole number of tokens, got fractional tokens: 0.{fractional_tokens}");
-            return Err(ValueInvalid.into());
-        }
-
-        global_config
-            .lamport_amount_config
-            .validate(&reserve_lamport)?;
-
-        global_config
-            .token_supply_config
-            .validate(&(token_supply / decimal_multiplier))?;
-
-        global_config.token_decimals_config.validate(&decimals)?;
-
-        let init_bonding_curve = (token_supply as f64)
-            .mul(global_config.init_bonding_curve)
-            .div(100_f64) as u64;
-
-        let amount_to_team = token_supply - init_bonding_curve;
-
-        // create token launch pda
-        bonding_curve.token_mint = token.key();
-        bonding_curve.creator = creator.key();
-        bonding_curve.init_lamport = reserve_lamport;
-        bonding_curve.reserve_lamport = reserve_lamport;
-        bonding_curve.reserve_token = init_bonding_curve;
-        bonding_curve.curve_limit = global_config.curve_limit;
-
-        // create global token account
-        associated_token::create(CpiContext::new(
-            self.associated_token_program.to_account_info(),
-            associated_token::Create {
-                payer: creator.to_account_info(),
-                associated_token: global_token_account.to_account_info(),
-                authority: global_vault.to_account_info(),
-                mint: token.to_account_info(),
-                token_program: self.token_program.to_account_info(),
-                system_program: self.system_program.to_account_info(),
+        launch_token(
+            LaunchAccounts {
+                global_config: &self.global_config,
+                global_vault: &self.global_vault,
+                creator: &self.creator,
+                token: &self.token,
+                bonding_curve,
+                token_metadata_account: &self.token_metadata_account,
+                global_token_account: &self.global_token_account,
+                team_wallet: &self.team_wallet,
+                team_wallet_ata: &self.team_wallet_ata,
+                system_program: &self.system_program,
+                rent: &self.rent,
+                token_program: &self.token_program,
+                associated_token_program: &self.associated_token_program,
+                mpl_token_metadata_program: &self.mpl_token_metadata_program,
+                quote_config: None, // the initial buy is paid in SOL
             },
-        ))?;
-        
-        // create team token account
-        anchor_spl::associated_token::create(CpiContext::new(
-            self.associated_token_program.to_account_info(),
-            anchor_spl::associated_token::Create {
-                payer: creator.to_account_info(),
-                associated_token: team_wallet_ata.to_account_info(),
-                authority: team_wallet.to_account_info(),
-                mint: token.to_account_info(),
-                system_program: self.system_program.to_account_info(),
-                token_program: self.token_program.to_account_info(),
-            },
-        ))?;
-        
+            params,
+            bumps,
+        )?;
+
         // create user token account
         if self.user_ata.data_is_empty() {
             anchor_spl::associated_token::create(CpiContext::new(
                 self.associated_token_program.to_account_info(),
                 anchor_spl::associated_token::Create {
-                    payer: creator.to_account_info(),
+                    payer: self.creator.to_account_info(),
                     associated_token: self.user_ata.to_account_info(),
-                    authority: creator.to_account_info(),
-                    mint: token.to_account_info(),
+                    authority: self.creator.to_account_info(),
+                    mint: self.token.to_account_info(),
                     system_program: self.system_program.to_account_info(),
                     token_program: self.token_program.to_account_info(),
                 },
             ))?;
         }
 
-        let signer_seeds: &[&[&[u8]]] = &[&[GLOBAL.as_bytes(), &[global_vault_bump]]];
-
-        // mint tokens to bonding curve & team
-        token::mint_to(
-            CpiContext::new_with_signer(
-                self.token_program.to_account_info(),
-                token::MintTo {
-                    mint: token.to_account_info(),
-                    to: global_token_account.to_account_info(),
-                    authority: global_vault.to_account_info(),
-                },
-                signer_seeds,
-            ),
-            init_bonding_curve,
-        )?;
-        token::mint_to(
-            CpiContext::new_with_signer(
-                self.token_program.to_account_info(),
-                token::MintTo {
-                    mint: token.to_account_info(),
-                    to: team_wallet_ata.to_account_info(),
-                    authority: global_vault.to_account_info(),
-                },
-                signer_seeds,
-            ),
-            amount_to_team,
-        )?;        
-
-        // create metadata
-        metadata::create_metadata_accounts_v3(
-            CpiContext::new_with_signer(
-                self.mpl_token_metadata_program.to_account_info(),
-                metadata::CreateMetadataAccountsV3 {
-                    metadata: self.token_metadata_account.to_account_info(),
-                    mint: token.to_account_info(),
-                    mint_authority: global_vault.to_account_info(),
-                    payer: creator.to_account_info(),
-                    update_authority: global_vault.to_account_info(),
-                    system_program: self.system_program.to_account_info(),
-                    rent: self.rent.to_account_info(),
-                },
-                signer_seeds,
-            ),
-            DataV2 {
-                name,
-                symbol,
-                uri,
-                seller_fee_basis_points: 0,
-                creators: None,
-                collection: None,
-                uses: None,
-            },
-            false,
-            true,
-            None,
-        )?;
-
-        //  revoke mint authority
-        token::set_authority(
-            CpiContext::new_with_signer(
-                self.token_program.to_account_info(),
-                token::SetAuthority {
-                    current_authority: global_vault.to_account_info(),
-                    account_or_mint: token.to_account_info(),
-                },
-                signer_seeds,
-            ),
-            AuthorityType::MintTokens,
-            None,
-        )?;
-
-        bonding_curve.is_completed = false;
-
-        msg!("NewToken: {} {}", 
-            bonding_curve.token_mint, 
-            bonding_curve.creator
-        );
-
         Ok(())
     }
 
     fn process_swap(
         &mut self,
+        bonding_curve: &mut BondingCurve,
         amount: u64,
-        direction: u8,
+        direction: Direction,
         minimum_receive_amount: u64,
         deadline: i64,
         global_vault_bump: u8,
@@ -358,19 +248,15 @@ impl<'info> LaunchAndSwap<'info> {
             current_timestamp <= deadline,
             PumpfunError::TransactionExpired
         );
-        
-        let bonding_curve = &mut self.bonding_curve;
 
         //  check curve is not completed - should always be false since we just created it
         require!(
-            !bonding_curve.is_completed,
+            !bonding_curve.is_completed(),
             PumpfunError::CurveAlreadyCompleted
         );
 
         let source = &mut self.global_vault.to_account_info();
         let token = &mut self.token;
-        let team_wallet = &mut self.team_wallet;
-        let team_wallet_ata = &mut self.team_wallet_ata;
         let user_ata = &mut self.user_ata;
 
         let signer_seeds: &[&[&[u8]]] = &[&[
@@ -380,13 +266,14 @@ impl<'info> LaunchAndSwap<'info> {
 
         // Perform the swap operation using the bonding curve's swap method
         let amount_out = bonding_curve.swap(
-            &self.global_config,
+            &self.bonding_curve.key(),
+            &*self.fee_config.load()?,
             token.as_ref(),
             &mut self.global_token_account.to_account_info(),
             user_ata,
             source,
-            team_wallet,
-            team_wallet_ata,
+            &mut self.fee_vault,
+            &mut self.trader_record,
             amount,
             direction,
             minimum_receive_amount,
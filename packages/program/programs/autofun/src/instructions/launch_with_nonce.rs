@@ -0,0 +1,181 @@
+use crate::{
+    constants::{BONDING_CURVE, CONFIG, GLOBAL, LAUNCH_COUNTER, METADATA, MINT},
+    errors::*,
+    instructions::launch::{launch_token, CurveBumps, LaunchAccounts, LaunchParams},
+    state::{BondingCurve, Config, LaunchCounter, QuoteConfig},
+};
+use anchor_lang::{prelude::*, solana_program::sysvar::SysvarId, system_program};
+use anchor_spl::{
+    associated_token::{self, AssociatedToken},
+    metadata::{self, Metadata},
+    token::{self, Mint, Token},
+};
+
+//  Same as Launch, but the mint is a PDA of the creator and a nonce
+//  so the address can be known before the launch without a mint keypair
+#[derive(Accounts)]
+#[instruction(nonce: u64, params: LaunchParams)]
+pub struct LaunchWithNonce<'info> {
+    #[account(
+        mut,
+        seeds = [CONFIG.as_bytes()],
+        bump,
+    )]
+    global_config: Box<Account<'info, Config>>,
+
+    /// CHECK: global vault pda which stores SOL
+    #[account(
+        mut,
+        seeds = [GLOBAL.as_bytes()],
+        bump,
+    )]
+    pub global_vault: AccountInfo<'info>,
+
+    #[account(mut)]
+    creator: Signer<'info>,
+
+    #[account(
+        init_if_needed,
+        payer = creator,
+        space = 8 + LaunchCounter::INIT_SPACE,
+        seeds = [LAUNCH_COUNTER.as_bytes(), creator.key().as_ref()],
+        bump
+    )]
+    launch_counter: Box<Account<'info, LaunchCounter>>,
+
+    #[account(
+        init,
+        payer = creator,
+        seeds = [MINT.as_bytes(), creator.key().as_ref(), &nonce.to_le_bytes()],
+        bump,
+        mint::decimals = params.decimals,
+        mint::authority = global_vault.key(),
+    )]
+    token: Box<Account<'info, Mint>>,
+
+    #[account(
+        init,
+        payer = creator,
+        space = 8 + BondingCurve::SIZE,
+        seeds = [BONDING_CURVE.as_bytes(), &token.key().to_bytes()],
+        bump
+    )]
+    bonding_curve: AccountLoader<'info, BondingCurve>,
+
+    /// CHECK: passed to token metadata program
+    #[account(
+        mut,
+        seeds = [
+            METADATA.as_bytes(),
+            metadata::ID.as_ref(),
+            token.key().as_ref(),
+        ],
+        bump,
+        seeds::program = metadata::ID
+    )]
+    token_metadata_account: UncheckedAccount<'info>,
+
+    /// CHECK: created in instruction
+    #[account(
+        mut,
+        seeds = [
+            global_vault.key().as_ref(),
+            token::spl_token::ID.as_ref(),
+            token.key().as_ref(),
+        ],
+        bump,
+        seeds::program = associated_token::ID
+    )]
+    global_token_account: UncheckedAccount<'info>,
+
+    #[account(address = system_program::ID)]
+    system_program: Program<'info, System>,
+
+    #[account(address = Rent::id())]
+    rent: Sysvar<'info, Rent>,
+
+    #[account(address = token::ID)]
+    token_program: Program<'info, Token>,
+
+    #[account(address = associated_token::ID)]
+    associated_token_program: Program<'info, AssociatedToken>,
+
+    #[account(address = metadata::ID)]
+    mpl_token_metadata_program: Program<'info, Metadata>,
+
+    //  team wallet
+    /// CHECK: should be same with the address in the global_config
+    #[account(
+        mut,
+        constraint = global_config.team_wallet == team_wallet.key() @PumpfunError::IncorrectAuthority
+    )]
+    pub team_wallet: AccountInfo<'info>,
+
+    /// CHECK: ata of team wallet
+    #[account(
+        mut,
+        seeds = [
+            team_wallet.key().as_ref(),
+            anchor_spl::token::spl_token::ID.as_ref(),
+            token.key().as_ref(),
+        ],
+        bump,
+        seeds::program = anchor_spl::associated_token::ID
+    )]
+    team_wallet_ata: AccountInfo<'info>,
+
+    //  config of the SPL quote mint, omit to launch a SOL curve
+    quote_config: Option<Box<Account<'info, QuoteConfig>>>,
+}
+
+impl<'info> LaunchWithNonce<'info> {
+    pub fn process(
+        &mut self,
+
+        // must equal the creator's current launch count
+        nonce: u64,
+
+        params: LaunchParams,
+
+        bumps: &LaunchWithNonceBumps,
+    ) -> Result<()> {
+        let launch_counter = &mut self.launch_counter;
+
+        require!(
+            nonce == launch_counter.launch_count,
+            PumpfunError::InvalidLaunchNonce
+        );
+
+        launch_counter.creator = self.creator.key();
+        launch_counter.launch_count = launch_counter
+            .launch_count
+            .checked_add(1)
+            .ok_or(PumpfunError::OverflowOrUnderflowOccurred)?;
+
+        launch_token(
+            LaunchAccounts {
+                global_config: &self.global_config,
+                global_vault: &self.global_vault,
+                creator: &self.creator,
+                token: &self.token,
+                bonding_curve: &mut *self.bonding_curve.load_init()?,
+                token_metadata_account: &self.token_metadata_account,
+                global_token_account: &self.global_token_account,
+                team_wallet: &self.team_wallet,
+                team_wallet_ata: &self.team_wallet_ata,
+                system_program: &self.system_program,
+                rent: &self.rent,
+                token_program: &self.token_program,
+                associated_token_program: &self.associated_token_program,
+                mpl_token_metadata_program: &self.mpl_token_metadata_program,
+                quote_config: self.quote_config.as_deref().map(|quote_config| &**quote_config),
+            },
+            params,
+            CurveBumps {
+                global_vault: bumps.global_vault,
+                bonding_curve: bumps.bonding_curve,
+                global_token_account: bumps.global_token_account,
+            },
+        )
+    }
+}
@@ -0,0 +1,93 @@
+use crate::{
+    constants::{BONDING_CURVE, GLOBAL},
+    errors::*,
+    state::BondingCurve,
+};
+use anchor_lang::{prelude::*, system_program, Discriminator};
+use anchor_spl::{associated_token, token::{self, Mint}};
+
+//  length of a curve launched with the original borsh layout:
+//  token_mint, creator, init_lamport, reserve_lamport, reserve_token, curve_limit, is_completed
+pub const LEGACY_BONDING_CURVE_LEN: usize = 8 + 32 + 32 + 8 * 4 + 1;
+
+//  grows a curve launched with the original layout to the current zero copy layout, callable by anyone
+//  the original fields are a prefix of the new layout so they keep their offsets,
+//  the stored bumps and twap are filled in here and every other field starts zeroed
+#[derive(Accounts)]
+pub struct MigrateBondingCurve<'info> {
+    #[account(mut)]
+    payer: Signer<'info>,
+
+    /// CHECK: checked by hand, the original layout is too short for AccountLoader<BondingCurve>
+    #[account(
+        mut,
+        seeds = [BONDING_CURVE.as_bytes(), &token_mint.key().to_bytes()],
+        bump,
+        owner = crate::ID
+    )]
+    bonding_curve: AccountInfo<'info>,
+
+    token_mint: Box<Account<'info, Mint>>,
+
+    #[account(address = system_program::ID)]
+    system_program: Program<'info, System>,
+}
+
+impl<'info> MigrateBondingCurve<'info> {
+    pub fn process(&mut self, bonding_curve_bump: u8) -> Result<()> {
+        let new_len = 8 + BondingCurve::SIZE;
+        {
+            let data = self.bonding_curve.try_borrow_data()?;
+            require!(
+                data.len() >= 8 && data[..8] == BondingCurve::DISCRIMINATOR,
+                PumpfunError::InvalidBondingCurveLayout
+            );
+        }
+
+        //  already migrated, or launched with the current layout
+        if self.bonding_curve.data_len() >= new_len {
+            return Ok(());
+        }
+        require!(
+            self.bonding_curve.data_len() == LEGACY_BONDING_CURVE_LEN,
+            PumpfunError::InvalidBondingCurveLayout
+        );
+
+        let lamports_needed = Rent::get()?
+            .minimum_balance(new_len)
+            .saturating_sub(self.bonding_curve.lamports());
+        if lamports_needed > 0 {
+            system_program::transfer(
+                CpiContext::new(
+                    self.system_program.to_account_info(),
+                    system_program::Transfer {
+                        from: self.payer.to_account_info(),
+                        to: self.bonding_curve.to_account_info(),
+                    },
+                ),
+                lamports_needed,
+            )?;
+        }
+
+        self.bonding_curve.realloc(new_len, true)?;
+
+        let (global_vault, _) = Pubkey::find_program_address(&[GLOBAL.as_bytes()], &crate::ID);
+        let (_, global_ata_bump) = Pubkey::find_program_address(
+            &[
+                global_vault.as_ref(),
+                token::spl_token::ID.as_ref(),
+                self.token_mint.key().as_ref(),
+            ],
+            &associated_token::ID,
+        );
+
+        let mut data = self.bonding_curve.try_borrow_mut_data()?;
+        let bonding_curve = bytemuck::from_bytes_mut::<BondingCurve>(&mut data[8..new_len]);
+        bonding_curve.bump = bonding_curve_bump;
+        bonding_curve.global_ata_bump = global_ata_bump;
+        //  first twap observation at the current price
+        bonding_curve.record_price(Clock::get()?.unix_timestamp);
+
+        Ok(())
+    }
+}
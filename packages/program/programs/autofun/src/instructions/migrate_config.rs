@@ -0,0 +1,115 @@
+use crate::{
+    constants::{CONFIG, FEE_CONFIG, FEE_VAULT, GLOBAL},
+    errors::*,
+    state::{AmountConfig, Config, FeeConfig, FeeVault},
+};
+use anchor_lang::{prelude::*, system_program, Discriminator};
+use borsh::BorshDeserialize;
+
+//  Config as written before the swap fees moved to FeeConfig
+#[derive(AnchorDeserialize)]
+pub struct LegacyConfig {
+    pub authority: Pubkey,
+    pub pending_authority: Pubkey,
+    pub team_wallet: Pubkey,
+    pub init_bonding_curve: f64,
+    pub platform_buy_fee: u128,
+    pub platform_sell_fee: u128,
+    pub curve_limit: u64,
+    pub lamport_amount_config: AmountConfig<u64>,
+    pub token_supply_config: AmountConfig<u64>,
+    pub token_decimals_config: AmountConfig<u8>,
+}
+
+//  rewrites a config written with the legacy layout and moves its swap fees to FeeConfig
+//  fee_config is created here, so the migration can only run once
+#[derive(Accounts)]
+pub struct MigrateConfig<'info> {
+    #[account(mut)]
+    authority: Signer<'info>,
+
+    /// CHECK: deserialized by hand, the legacy layout does not load as Account<Config>
+    #[account(
+        mut,
+        seeds = [CONFIG.as_bytes()],
+        bump,
+        owner = crate::ID
+    )]
+    config: AccountInfo<'info>,
+
+    /// CHECK: global vault pda which stores SOL
+    #[account(
+        seeds = [GLOBAL.as_bytes()],
+        bump,
+    )]
+    pub global_vault: AccountInfo<'info>,
+
+    //  accrues the fees of SOL curves
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = 8 + FeeVault::INIT_SPACE,
+        seeds = [FEE_VAULT.as_bytes(), Pubkey::default().as_ref()],
+        bump
+    )]
+    fee_vault: Box<Account<'info, FeeVault>>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + FeeConfig::SIZE,
+        seeds = [FEE_CONFIG.as_bytes()],
+        bump
+    )]
+    fee_config: AccountLoader<'info, FeeConfig>,
+
+    #[account(address = system_program::ID)]
+    system_program: Program<'info, System>,
+}
+
+impl<'info> MigrateConfig<'info> {
+    pub fn process(&mut self, fee_config_bump: u8, fee_vault_bump: u8, global_vault_bump: u8) -> Result<()> {
+        let legacy_config = {
+            let data = self.config.try_borrow_data()?;
+            if data.len() < 8 || data[0..8] != Config::DISCRIMINATOR {
+                return err!(PumpfunError::IncorrectConfigAccount);
+            }
+            LegacyConfig::deserialize(&mut &data[8..])?
+        };
+
+        if legacy_config.authority != self.authority.key() {
+            return err!(PumpfunError::IncorrectAuthority);
+        }
+
+        let new_config = Config {
+            authority: legacy_config.authority,
+            pending_authority: legacy_config.pending_authority,
+            team_wallet: legacy_config.team_wallet,
+            init_bonding_curve: legacy_config.init_bonding_curve,
+            curve_limit: legacy_config.curve_limit,
+            lamport_amount_config: legacy_config.lamport_amount_config,
+            token_supply_config: legacy_config.token_supply_config,
+            token_decimals_config: legacy_config.token_decimals_config,
+        };
+        let serialized_config =
+            [&Config::DISCRIMINATOR, new_config.try_to_vec()?.as_slice()].concat();
+
+        //  the new layout is shorter, the leftover rent stays on the account
+        self.config.realloc(serialized_config.len(), false)?;
+        self.config
+            .try_borrow_mut_data()?
+            .copy_from_slice(serialized_config.as_slice());
+
+        let fee_config = &mut self.fee_config.load_init()?;
+        fee_config.platform_buy_fee = u64::try_from(legacy_config.platform_buy_fee)
+            .map_err(|_| PumpfunError::OverflowOrUnderflowOccurred)?;
+        fee_config.platform_sell_fee = u64::try_from(legacy_config.platform_sell_fee)
+            .map_err(|_| PumpfunError::OverflowOrUnderflowOccurred)?;
+        fee_config.bump = fee_config_bump;
+        fee_config.global_vault_bump = global_vault_bump;
+
+        self.fee_vault.bump = fee_vault_bump;
+
+        Ok(())
+    }
+}
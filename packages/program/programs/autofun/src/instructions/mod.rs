@@ -1,5 +1,7 @@
 pub mod configure;
 pub use configure::*;
+pub mod configure_quote;
+pub use configure_quote::*;
 pub mod nominate_authority;
 pub use nominate_authority::*;
 pub mod accept_authority;
@@ -8,7 +10,43 @@ pub mod launch;
 pub use launch::*;
 pub mod swap;
 pub use swap::*;
+pub mod swap_v2;
+pub use swap_v2::*;
+pub mod swap_quote;
+pub use swap_quote::*;
 pub mod withdraw;
 pub use withdraw::*;
+pub mod withdraw_quote;
+pub use withdraw_quote::*;
 pub mod launch_and_swap;
-pub use launch_and_swap::*;
\ No newline at end of file
+pub use launch_and_swap::*;
+pub mod launch_with_nonce;
+pub use launch_with_nonce::*;
+pub mod unwrap_vault_wsol;
+pub use unwrap_vault_wsol::*;
+pub mod sweep_fees;
+pub use sweep_fees::*;
+pub mod get_twap;
+pub use get_twap::*;
+pub mod refund;
+pub use refund::*;
+pub mod open_auction;
+pub use open_auction::*;
+pub mod place_bid;
+pub use place_bid::*;
+pub mod settle_auction;
+pub use settle_auction::*;
+pub mod claim_auction;
+pub use claim_auction::*;
+pub mod place_order;
+pub use place_order::*;
+pub mod fill_order;
+pub use fill_order::*;
+pub mod cancel_order;
+pub use cancel_order::*;
+pub mod migrate_bonding_curve;
+pub use migrate_bonding_curve::*;
+pub mod migrate_config;
+pub use migrate_config::*;
+pub mod close_trader_record;
+pub use close_trader_record::*;
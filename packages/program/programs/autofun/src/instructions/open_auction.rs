@@ -0,0 +1,69 @@
+use crate::{
+    constants::{AUCTION, BONDING_CURVE},
+    errors::*,
+    state::{Auction, BondingCurve},
+};
+use anchor_lang::{prelude::*, system_program};
+use anchor_spl::token::Mint;
+
+//  Creator turns a scheduled launch into a batch auction before trading starts
+//  swaps are rejected until settle_auction clears the bids
+#[derive(Accounts)]
+pub struct OpenAuction<'info> {
+    #[account(mut)]
+    creator: Signer<'info>,
+
+    token_mint: Box<Account<'info, Mint>>,
+
+    #[account(
+        mut,
+        seeds = [BONDING_CURVE.as_bytes(), &token_mint.key().to_bytes()],
+        bump = bonding_curve.load()?.bump,
+        constraint = bonding_curve.load()?.creator == creator.key() @PumpfunError::IncorrectAuthority
+    )]
+    bonding_curve: AccountLoader<'info, BondingCurve>,
+
+    #[account(
+        init,
+        payer = creator,
+        space = 8 + Auction::INIT_SPACE,
+        seeds = [AUCTION.as_bytes(), bonding_curve.key().as_ref()],
+        bump
+    )]
+    auction: Box<Account<'info, Auction>>,
+
+    #[account(address = system_program::ID)]
+    system_program: Program<'info, System>,
+}
+
+impl<'info> OpenAuction<'info> {
+    pub fn process(&mut self, ends_at: i64, auction_bump: u8) -> Result<()> {
+        let now = Clock::get()?.unix_timestamp;
+        let mut bonding_curve = self.bonding_curve.load_mut()?;
+
+        //  bids are escrowed in SOL
+        require!(bonding_curve.is_native_quote(), PumpfunError::IncorrectQuoteMint);
+
+        //  nobody can have traded yet, and the auction has to settle before the curve expires
+        require!(
+            now < bonding_curve.trading_starts_at
+                && ends_at > now
+                && (bonding_curve.expires_at == 0 || ends_at < bonding_curve.expires_at),
+            PumpfunError::InvalidAuctionWindow
+        );
+
+        bonding_curve.auction_ends_at = ends_at;
+
+        let auction = &mut self.auction;
+        auction.bonding_curve = self.bonding_curve.key();
+        auction.token_mint = self.token_mint.key();
+        auction.ends_at = ends_at;
+        auction.total_bids = 0;
+        auction.lamports_used = 0;
+        auction.tokens_out = 0;
+        auction.is_settled = false;
+        auction.bump = auction_bump;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,74 @@
+use crate::{
+    constants::{AUCTION, BID},
+    errors::*,
+    events::AuctionBid,
+    state::{Auction, Bid},
+    utils::sol_transfer_from_user,
+};
+use anchor_lang::{prelude::*, system_program};
+
+//  Escrows a SOL bid in the auction during its commit window
+#[derive(Accounts)]
+pub struct PlaceBid<'info> {
+    #[account(
+        mut,
+        seeds = [AUCTION.as_bytes(), auction.bonding_curve.as_ref()],
+        bump = auction.bump
+    )]
+    auction: Box<Account<'info, Auction>>,
+
+    #[account(
+        init_if_needed,
+        payer = bidder,
+        space = 8 + Bid::INIT_SPACE,
+        seeds = [BID.as_bytes(), auction.key().as_ref(), bidder.key().as_ref()],
+        bump
+    )]
+    bid: Box<Account<'info, Bid>>,
+
+    #[account(mut)]
+    bidder: Signer<'info>,
+
+    #[account(address = system_program::ID)]
+    system_program: Program<'info, System>,
+}
+
+impl<'info> PlaceBid<'info> {
+    pub fn process(&mut self, amount: u64) -> Result<()> {
+        require!(amount > 0, PumpfunError::InvalidAmount);
+        require!(
+            Clock::get()?.unix_timestamp < self.auction.ends_at,
+            PumpfunError::AuctionClosed
+        );
+
+        sol_transfer_from_user(
+            &self.bidder,
+            self.auction.to_account_info(),
+            &self.system_program,
+            amount,
+        )?;
+
+        let bid = &mut self.bid;
+        bid.auction = self.auction.key();
+        bid.bidder = self.bidder.key();
+        bid.amount = bid
+            .amount
+            .checked_add(amount)
+            .ok_or(PumpfunError::OverflowOrUnderflowOccurred)?;
+
+        let auction = &mut self.auction;
+        auction.total_bids = auction
+            .total_bids
+            .checked_add(amount)
+            .ok_or(PumpfunError::OverflowOrUnderflowOccurred)?;
+
+        emit!(AuctionBid {
+            auction: auction.key(),
+            bidder: self.bidder.key(),
+            amount,
+            total_bids: auction.total_bids,
+        });
+
+        Ok(())
+    }
+}
@@ -0,0 +1,181 @@
+use crate::{
+    constants::{BONDING_CURVE, ORDER, TRADER_RECORD},
+    errors::*,
+    events::OrderPlaced,
+    state::{BondingCurve, Direction, Order, TraderRecord},
+    utils::{sol_transfer_from_user, token_transfer_user},
+};
+use anchor_lang::{prelude::*, system_program};
+use anchor_spl::{
+    associated_token::{self, AssociatedToken},
+    token::{self, Mint, Token, TokenAccount},
+};
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct OrderParams {
+    pub direction: Direction,
+    pub amount: u64, //  lamports in when buying, tokens in when selling
+    pub limit_price: u64, //  in spot_price units, see BondingCurve::spot_price
+    pub keeper_tip: u64, //  lamports
+    pub expires_at: i64,
+}
+
+//  Escrows a limit order for keepers to fill through fill_order
+//  an owner can have many orders on a curve, one per nonce
+#[derive(Accounts)]
+#[instruction(nonce: u64)]
+pub struct PlaceOrder<'info> {
+    #[account(mut)]
+    owner: Signer<'info>,
+
+    token_mint: Box<Account<'info, Mint>>,
+
+    #[account(
+        seeds = [BONDING_CURVE.as_bytes(), &token_mint.key().to_bytes()],
+        bump = bonding_curve.load()?.bump
+    )]
+    bonding_curve: AccountLoader<'info, BondingCurve>,
+
+    #[account(
+        init,
+        payer = owner,
+        space = 8 + Order::INIT_SPACE,
+        seeds = [ORDER.as_bytes(), bonding_curve.key().as_ref(), owner.key().as_ref(), &nonce.to_le_bytes()],
+        bump
+    )]
+    order: Box<Account<'info, Order>>,
+
+    //  the owner pays for their trader record here, fill_order only updates it
+    #[account(
+        init_if_needed,
+        payer = owner,
+        space = 8 + TraderRecord::INIT_SPACE,
+        seeds = [TRADER_RECORD.as_bytes(), bonding_curve.key().as_ref(), owner.key().as_ref()],
+        bump
+    )]
+    trader_record: Box<Account<'info, TraderRecord>>,
+
+    /// CHECK: ata of the order, created here for a sell order to hold its tokens and closed when
+    /// the order is filled or cancelled, buy orders leave it out
+    #[account(mut)]
+    order_token_account: Option<UncheckedAccount<'info>>,
+
+    #[account(
+        mut,
+        token::mint = token_mint,
+        token::authority = owner
+    )]
+    owner_token_account: Box<Account<'info, TokenAccount>>,
+
+    #[account(address = system_program::ID)]
+    system_program: Program<'info, System>,
+
+    #[account(address = token::ID)]
+    token_program: Program<'info, Token>,
+
+    #[account(address = associated_token::ID)]
+    associated_token_program: Program<'info, AssociatedToken>,
+}
+
+impl<'info> PlaceOrder<'info> {
+    pub fn process(&mut self, nonce: u64, params: OrderParams, order_bump: u8) -> Result<()> {
+        let OrderParams {
+            direction,
+            amount,
+            limit_price,
+            keeper_tip,
+            expires_at,
+        } = params;
+
+        require!(
+            amount > 0 && limit_price > 0 && expires_at > Clock::get()?.unix_timestamp,
+            PumpfunError::InvalidOrder
+        );
+
+        {
+            let bonding_curve = self.bonding_curve.load()?;
+
+            require!(
+                !bonding_curve.is_completed(),
+                PumpfunError::CurveAlreadyCompleted
+            );
+
+            //  orders escrow SOL, curves quoted in an SPL mint are not supported
+            require!(
+                bonding_curve.is_native_quote(),
+                PumpfunError::IncorrectQuoteMint
+            );
+        }
+
+        match direction {
+            Direction::Buy => {
+                let escrow = amount
+                    .checked_add(keeper_tip)
+                    .ok_or(PumpfunError::OverflowOrUnderflowOccurred)?;
+                sol_transfer_from_user(
+                    &self.owner,
+                    self.order.to_account_info(),
+                    &self.system_program,
+                    escrow,
+                )?;
+            }
+            Direction::Sell => {
+                let Some(order_token_account) = &self.order_token_account else {
+                    return err!(PumpfunError::MissingOrderTokenAccount);
+                };
+
+                //  the associated token program checks the address
+                associated_token::create(CpiContext::new(
+                    self.associated_token_program.to_account_info(),
+                    associated_token::Create {
+                        payer: self.owner.to_account_info(),
+                        associated_token: order_token_account.to_account_info(),
+                        authority: self.order.to_account_info(),
+                        mint: self.token_mint.to_account_info(),
+                        system_program: self.system_program.to_account_info(),
+                        token_program: self.token_program.to_account_info(),
+                    },
+                ))?;
+
+                token_transfer_user(
+                    self.owner_token_account.to_account_info(),
+                    &self.owner,
+                    order_token_account.to_account_info(),
+                    &self.token_program,
+                    amount,
+                )?;
+                sol_transfer_from_user(
+                    &self.owner,
+                    self.order.to_account_info(),
+                    &self.system_program,
+                    keeper_tip,
+                )?;
+            }
+        }
+
+        let order = &mut self.order;
+        order.owner = self.owner.key();
+        order.bonding_curve = self.bonding_curve.key();
+        order.token_mint = self.token_mint.key();
+        order.direction = direction;
+        order.amount = amount;
+        order.limit_price = limit_price;
+        order.keeper_tip = keeper_tip;
+        order.expires_at = expires_at;
+        order.nonce = nonce;
+        order.bump = order_bump;
+
+        emit!(OrderPlaced {
+            order: order.key(),
+            owner: order.owner,
+            mint: order.token_mint,
+            direction: direction.into(),
+            amount,
+            limit_price,
+            keeper_tip,
+            expires_at,
+        });
+
+        Ok(())
+    }
+}
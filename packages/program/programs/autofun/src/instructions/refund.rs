@@ -0,0 +1,143 @@
+use crate::{
+    constants::{BONDING_CURVE, GLOBAL, TRADER_RECORD},
+    errors::*,
+    events::Refunded,
+    state::{BondingCurve, TraderRecord},
+    utils::{sol_transfer_with_signer, token_transfer_with_signer},
+};
+use anchor_lang::{prelude::*, system_program};
+use anchor_spl::token::{self, Mint, Token, TokenAccount};
+
+//  Burns a holder's tokens of an expired curve for a pro-rata share of the real SOL it raised
+//  a holder can refund at most the tokens their trader record shows they bought
+#[derive(Accounts)]
+pub struct Refund<'info> {
+    #[account(mut)]
+    token_mint: Box<Account<'info, Mint>>,
+
+    #[account(
+        mut,
+        seeds = [BONDING_CURVE.as_bytes(), &token_mint.key().to_bytes()],
+        bump = bonding_curve.load()?.bump
+    )]
+    bonding_curve: AccountLoader<'info, BondingCurve>,
+
+    /// CHECK: global vault pda which stores SOL
+    #[account(
+        mut,
+        seeds = [GLOBAL.as_bytes()],
+        bump,
+    )]
+    pub global_vault: AccountInfo<'info>,
+
+    #[account(
+        mut,
+        token::mint = token_mint,
+        token::authority = user
+    )]
+    user_token_account: Box<Account<'info, TokenAccount>>,
+
+    #[account(mut)]
+    pub user: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [TRADER_RECORD.as_bytes(), bonding_curve.key().as_ref(), user.key().as_ref()],
+        bump
+    )]
+    trader_record: Box<Account<'info, TraderRecord>>,
+
+    //  required for a curve quoted in an SPL mint
+    #[account(
+        mut,
+        associated_token::mint = bonding_curve.load()?.quote_mint,
+        associated_token::authority = global_vault
+    )]
+    quote_vault: Option<Box<Account<'info, TokenAccount>>>,
+
+    //  required for a curve quoted in an SPL mint
+    #[account(
+        mut,
+        token::mint = bonding_curve.load()?.quote_mint,
+        token::authority = user
+    )]
+    user_quote_account: Option<Box<Account<'info, TokenAccount>>>,
+
+    #[account(address = system_program::ID)]
+    system_program: Program<'info, System>,
+
+    #[account(address = token::ID)]
+    token_program: Program<'info, Token>,
+}
+
+impl<'info> Refund<'info> {
+    pub fn process(&mut self, amount: u64, global_vault_bump: u8) -> Result<()> {
+        let mut bonding_curve = self.bonding_curve.load_mut()?;
+
+        require!(
+            bonding_curve.is_expired(Clock::get()?.unix_timestamp),
+            PumpfunError::CurveNotExpired
+        );
+
+        self.trader_record.take_refund(amount)?;
+        let refund_amount = bonding_curve.refund_amount(amount)?;
+
+        token::burn(
+            CpiContext::new(
+                self.token_program.to_account_info(),
+                token::Burn {
+                    mint: self.token_mint.to_account_info(),
+                    from: self.user_token_account.to_account_info(),
+                    authority: self.user.to_account_info(),
+                },
+            ),
+            amount,
+        )?;
+
+        bonding_curve.reserve_lamport = bonding_curve
+            .reserve_lamport
+            .checked_sub(refund_amount)
+            .ok_or(PumpfunError::OverflowOrUnderflowOccurred)?;
+        //  burned tokens leave the outstanding supply, keeping the share of later refunds unchanged
+        bonding_curve.refunded_token = bonding_curve
+            .refunded_token
+            .checked_add(amount)
+            .ok_or(PumpfunError::OverflowOrUnderflowOccurred)?;
+
+        let signer_seeds: &[&[&[u8]]] = &[&[GLOBAL.as_bytes(), &[global_vault_bump]]];
+
+        if bonding_curve.is_native_quote() {
+            sol_transfer_with_signer(
+                self.global_vault.clone(),
+                self.user.to_account_info(),
+                &self.system_program,
+                signer_seeds,
+                refund_amount,
+            )?;
+        } else {
+            let (Some(quote_vault), Some(user_quote_account)) =
+                (&self.quote_vault, &self.user_quote_account)
+            else {
+                return err!(PumpfunError::IncorrectQuoteMint);
+            };
+
+            token_transfer_with_signer(
+                quote_vault.to_account_info(),
+                self.global_vault.clone(),
+                user_quote_account.to_account_info(),
+                &self.token_program,
+                signer_seeds,
+                refund_amount,
+            )?;
+        }
+
+        emit!(Refunded {
+            user: self.user.key(),
+            mint: self.token_mint.key(),
+            token_amount: amount,
+            refund_amount,
+        });
+
+        Ok(())
+    }
+}
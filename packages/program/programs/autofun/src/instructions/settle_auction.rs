@@ -0,0 +1,114 @@
+use crate::{
+    constants::{AUCTION, BONDING_CURVE, FEE_CONFIG, FEE_VAULT, GLOBAL},
+    errors::*,
+    events::AuctionSettled,
+    state::{Auction, BondingCurve, BondingCurveAccount, Direction, FeeConfig, FeeVault, SwapAmounts, TraderRecord},
+    utils::sol_transfer_from_program_account,
+};
+use anchor_lang::prelude::*;
+use anchor_spl::token::Mint;
+
+//  Clears all bids after the commit window as one buy on the curve, callable by anyone
+//  every bidder gets the same price, tokens stay in the global ata until claimed
+#[derive(Accounts)]
+pub struct SettleAuction<'info> {
+    #[account(
+        seeds = [FEE_CONFIG.as_bytes()],
+        bump = fee_config.load()?.bump,
+    )]
+    fee_config: AccountLoader<'info, FeeConfig>,
+
+    token_mint: Box<Account<'info, Mint>>,
+
+    #[account(
+        mut,
+        seeds = [BONDING_CURVE.as_bytes(), &token_mint.key().to_bytes()],
+        bump = bonding_curve.load()?.bump
+    )]
+    bonding_curve: AccountLoader<'info, BondingCurve>,
+
+    #[account(
+        mut,
+        seeds = [AUCTION.as_bytes(), bonding_curve.key().as_ref()],
+        bump = auction.bump
+    )]
+    auction: Box<Account<'info, Auction>>,
+
+    //  accrues the swap fee, swept to the team wallet by sweep_fees
+    #[account(
+        mut,
+        seeds = [FEE_VAULT.as_bytes(), bonding_curve.load()?.quote_mint.as_ref()],
+        bump = fee_vault.bump
+    )]
+    fee_vault: Box<Account<'info, FeeVault>>,
+
+    /// CHECK: global vault pda which stores SOL
+    #[account(
+        mut,
+        seeds = [GLOBAL.as_bytes()],
+        bump = fee_config.load()?.global_vault_bump,
+    )]
+    pub global_vault: AccountInfo<'info>,
+}
+
+impl<'info> SettleAuction<'info> {
+    pub fn process(&mut self) -> Result<()> {
+        let now = Clock::get()?.unix_timestamp;
+        let auction_key = self.auction.key();
+        let auction = &mut self.auction;
+
+        require!(!auction.is_settled, PumpfunError::AuctionAlreadySettled);
+        require!(now >= auction.ends_at, PumpfunError::AuctionNotEnded);
+
+        let bonding_curve_key = self.bonding_curve.key();
+        let mut bonding_curve = self.bonding_curve.load_mut()?;
+        bonding_curve.auction_ends_at = 0;
+
+        //  a curve completed by the creator's launch buy refunds every bid
+        if auction.total_bids > 0 && !bonding_curve.is_completed() {
+            //  the auction trades as a single trader
+            let mut trader_record = TraderRecord {
+                bonding_curve: bonding_curve_key,
+                user: auction_key,
+                first_trade_ts: 0,
+                bought_token: 0,
+            };
+
+            //  bids above the curve limit are not spent and are refunded at claim
+            let SwapAmounts { amount_in, amount_out, fee: _ } = bonding_curve.apply_swap(
+                &bonding_curve_key,
+                &*self.fee_config.load()?,
+                &self.token_mint,
+                &mut self.fee_vault,
+                &mut trader_record,
+                auction.total_bids,
+                Direction::Buy,
+                0,
+                now,
+                &auction_key,
+            )?;
+            bonding_curve.log_completion();
+
+            sol_transfer_from_program_account(
+                auction.to_account_info(),
+                self.global_vault.clone(),
+                amount_in,
+            )?;
+
+            auction.lamports_used = amount_in;
+            auction.tokens_out = amount_out;
+        }
+
+        auction.is_settled = true;
+
+        emit!(AuctionSettled {
+            auction: auction_key,
+            mint: self.token_mint.key(),
+            total_bids: auction.total_bids,
+            lamports_used: auction.lamports_used,
+            tokens_out: auction.tokens_out,
+        });
+
+        Ok(())
+    }
+}
@@ -1,56 +1,54 @@
 use anchor_lang::{system_program, prelude::*};
 use anchor_spl::{
     associated_token::{self, AssociatedToken},
-    token::{self, Mint, Token},
+    token::{self, Mint, Token, TokenAccount},
 };
 use crate::{
-    constants::{BONDING_CURVE, CONFIG, GLOBAL}, 
+    constants::{BONDING_CURVE, FEE_CONFIG, FEE_VAULT, GLOBAL, TRADER_RECORD}, 
     errors::PumpfunError, 
-    state::{BondingCurve, Config, BondingCurveAccount}
+    state::{BondingCurve, BondingCurveAccount, FeeConfig, Direction, FeeVault, SwapAmounts, TraderRecord},
+    utils::{log_swap, sol_transfer_with_signer, token_transfer_user, token_transfer_with_signer, unwrap_global_wsol},
 };
 
 #[derive(Accounts)]
 pub struct Swap<'info> {
+    //  bumps are read from stored fields instead of re-derived
     #[account(
-        seeds = [CONFIG.as_bytes()],
-        bump,
+        seeds = [FEE_CONFIG.as_bytes()],
+        bump = fee_config.load()?.bump,
     )]
-    global_config: Box<Account<'info, Config>>,
+    fee_config: AccountLoader<'info, FeeConfig>,
     
-    //  team wallet
-    /// CHECK: should be same with the address in the global_config
     #[account(
         mut,
-        constraint = global_config.team_wallet == team_wallet.key() @PumpfunError::IncorrectAuthority
+        seeds = [BONDING_CURVE.as_bytes(), &token_mint.key().to_bytes()], 
+        bump = bonding_curve.load()?.bump
     )]
-    pub team_wallet: AccountInfo<'info>,
+    bonding_curve: AccountLoader<'info, BondingCurve>,
 
-    /// CHECK: ata of team wallet
+    //  accrues the swap fee, swept to the team wallet by sweep_fees
     #[account(
         mut,
-        seeds = [
-            team_wallet.key().as_ref(),
-            anchor_spl::token::spl_token::ID.as_ref(),
-            token_mint.key().as_ref(),
-        ],
-        bump,
-        seeds::program = anchor_spl::associated_token::ID
+        seeds = [FEE_VAULT.as_bytes(), bonding_curve.load()?.quote_mint.as_ref()],
+        bump = fee_vault.bump
     )]
-    team_wallet_ata: AccountInfo<'info>,
-
+    fee_vault: Box<Account<'info, FeeVault>>,
 
+    //  created on the user's first trade on this curve, counts unique traders
     #[account(
-        mut,
-        seeds = [BONDING_CURVE.as_bytes(), &token_mint.key().to_bytes()], 
+        init_if_needed,
+        payer = user,
+        space = 8 + TraderRecord::INIT_SPACE,
+        seeds = [TRADER_RECORD.as_bytes(), bonding_curve.key().as_ref(), user.key().as_ref()],
         bump
     )]
-    bonding_curve: Account<'info, BondingCurve>,
+    trader_record: Box<Account<'info, TraderRecord>>,
 
     /// CHECK: global vault pda which stores SOL
     #[account(
         mut,
         seeds = [GLOBAL.as_bytes()],
-        bump,
+        bump = fee_config.load()?.global_vault_bump,
     )]
     pub global_vault: AccountInfo<'info>,
 
@@ -64,23 +62,19 @@ pub struct Swap<'info> {
             anchor_spl::token::spl_token::ID.as_ref(),
             token_mint.key().as_ref(),
         ],
-        bump,
+        bump = bonding_curve.load()?.global_ata_bump,
         seeds::program = anchor_spl::associated_token::ID
     )]
     global_ata: AccountInfo<'info>,
 
-    /// CHECK: ata of user
+    //  any token account of the user for the mint, not only the ata
+    //  it must exist, clients create the ata idempotently before a first buy
     #[account(
         mut,
-        seeds = [
-            user.key().as_ref(),
-            anchor_spl::token::spl_token::ID.as_ref(),
-            token_mint.key().as_ref(),
-        ],
-        bump,
-        seeds::program = anchor_spl::associated_token::ID
+        token::mint = token_mint,
+        token::authority = user
     )]
-    user_ata: AccountInfo<'info>,
+    user_token_account: Box<Account<'info, TokenAccount>>,
 
     #[account(mut)]
     pub user: Signer<'info>,
@@ -91,12 +85,34 @@ pub struct Swap<'info> {
     #[account(address = token::ID)]
     pub token_program: Program<'info, Token>,
 
+    //  wrapped SOL account of the user, pays or receives SOL instead of the user wallet when passed
+    #[account(
+        mut,
+        token::mint = spl_token::native_mint::ID,
+        token::authority = user
+    )]
+    user_wsol_account: Option<Box<Account<'info, TokenAccount>>>,
+
+    //  wrapped SOL ata of global vault, required with user_wsol_account
+    #[account(
+        mut,
+        associated_token::mint = spl_token::native_mint::ID,
+        associated_token::authority = global_vault
+    )]
+    global_wsol_account: Option<Box<Account<'info, TokenAccount>>>,
+
+    //  required with user_wsol_account, to unwrap wrapped SOL paid in back into the global vault
+    #[account(address = spl_token::native_mint::ID)]
+    native_mint: Option<Box<Account<'info, Mint>>>,
+
     #[account(address = associated_token::ID)]
-    pub associated_token_program: Program<'info, AssociatedToken>,
+    associated_token_program: Option<Program<'info, AssociatedToken>>,
 }
 
 impl<'info> Swap<'info> { 
-pub fn process(&mut self, amount: u64, direction: u8, minimum_receive_amount: u64, deadline: i64, global_vault_bump:u8) -> Result<u64> {
+pub fn process(&mut self, amount: u64, direction: u8, minimum_receive_amount: u64, deadline: i64) -> Result<u64> {
+    let direction = Direction::try_from(direction)?;
+
     // Check deadline hasn't passed
     let current_timestamp = Clock::get()?.unix_timestamp;
     require!(
@@ -104,67 +120,60 @@ pub fn process(&mut self, amount: u64, direction: u8, minimum_receive_amount: u6
         PumpfunError::TransactionExpired
     );
     
-    let bonding_curve = &mut self.bonding_curve;
+    {
+        let bonding_curve = self.bonding_curve.load()?;
 
-    //  check curve is not completed
-    require!(
-        !bonding_curve.is_completed,
-        PumpfunError::CurveAlreadyCompleted
-    );
+        //  check curve is not completed
+        require!(
+            !bonding_curve.is_completed(),
+            PumpfunError::CurveAlreadyCompleted
+        );
 
-    let source = &mut self.global_vault.to_account_info();
+        //  curves quoted in an SPL mint trade through swap_quote
+        require!(
+            bonding_curve.is_native_quote(),
+            PumpfunError::IncorrectQuoteMint
+        );
 
-    let token = &mut self.token_mint;
-    let team_wallet = &mut self.team_wallet;
-    let team_wallet_ata = &mut self.team_wallet_ata;
-    let user_ata = &mut self.user_ata;
-
-    //  create user wallet ata, if it doean't exit
-    if user_ata.data_is_empty() {
-        anchor_spl::associated_token::create(CpiContext::new(
-            self.associated_token_program.to_account_info(),
-            anchor_spl::associated_token::Create {
-                payer: self.user.to_account_info(),
-                associated_token: user_ata.to_account_info(),
-                authority: self.user.to_account_info(),
-
-                mint: token.to_account_info(),
-                system_program: self.system_program.to_account_info(),
-                token_program: self.token_program.to_account_info(),
-            }
-        ))?;
+        //  check trading has started for scheduled launches
+        require!(
+            current_timestamp >= bonding_curve.trading_starts_at,
+            PumpfunError::TradingNotStarted
+        );
     }
 
-    //  create team wallet ata, if it doesn't exist
-    if team_wallet_ata.data_is_empty() {
-        anchor_spl::associated_token::create(CpiContext::new(
-            self.associated_token_program.to_account_info(),
-            anchor_spl::associated_token::Create {
-                payer: self.user.to_account_info(),
-                associated_token: team_wallet_ata.to_account_info(),
-                authority: team_wallet.to_account_info(),
-
-                mint: token.to_account_info(),
-                system_program: self.system_program.to_account_info(),
-                token_program: self.token_program.to_account_info(),
-            }
-        ))?;
-    }
+    let source = &mut self.global_vault.to_account_info();
+
+    let token = &mut self.token_mint;
+    let user_token_account = &mut self.user_token_account.to_account_info();
 
+    let global_vault_bump = self.fee_config.load()?.global_vault_bump;
     let signer_seeds: &[&[&[u8]]] = &[&[
         GLOBAL.as_bytes(),
         &[global_vault_bump],
     ]];
 
+    if self.user_wsol_account.is_some() {
+        return self.process_wsol(
+            amount,
+            direction,
+            minimum_receive_amount,
+            deadline,
+            signer_seeds,
+        );
+    }
     
+    let bonding_curve_key = self.bonding_curve.key();
+    let mut bonding_curve = self.bonding_curve.load_mut()?;
     let amount_out = bonding_curve.swap(
-        &self.global_config,
+        &bonding_curve_key,
+        &*self.fee_config.load()?,
         token.as_ref(),
         &mut self.global_ata,
-        user_ata,
+        user_token_account,
         source,
-        team_wallet,
-        team_wallet_ata,
+        &mut self.fee_vault,
+        &mut self.trader_record,
         amount,
         direction,
         minimum_receive_amount,
@@ -180,4 +189,108 @@ pub fn process(&mut self, amount: u64, direction: u8, minimum_receive_amount: u6
     Ok(amount_out)
 }
 
-}
\ No newline at end of file
+//  same as the native path, with the user's SOL side in wrapped SOL
+//  wrapped SOL paid in is unwrapped into the global vault right away, so the vault holds all
+//  of the curve's SOL natively, and wrapped SOL paid out is wrapped from the vault
+fn process_wsol(
+    &mut self,
+    amount: u64,
+    direction: Direction,
+    minimum_receive_amount: u64,
+    deadline: i64,
+    signer_seeds: &[&[&[u8]]],
+) -> Result<u64> {
+    let (Some(user_wsol_account), Some(global_wsol_account), Some(native_mint), Some(associated_token_program)) = (
+        &self.user_wsol_account,
+        &self.global_wsol_account,
+        &self.native_mint,
+        &self.associated_token_program,
+    ) else {
+        return err!(PumpfunError::MissingWsolAccount);
+    };
+
+    let bonding_curve_key = self.bonding_curve.key();
+    let mut bonding_curve = self.bonding_curve.load_mut()?;
+    let SwapAmounts { amount_in, amount_out, fee: _ } = bonding_curve.apply_swap(
+        &bonding_curve_key,
+        &*self.fee_config.load()?,
+        &self.token_mint,
+        &mut self.fee_vault,
+        &mut self.trader_record,
+        amount,
+        direction,
+        minimum_receive_amount,
+        deadline,
+        self.user.key,
+    )?;
+    bonding_curve.log_completion();
+
+    if direction == Direction::Sell { // Selling Tokens for wrapped SOL
+        // Transfer tokens from user to pool
+        token_transfer_user(
+            self.user_token_account.to_account_info(),
+            &self.user,
+            self.global_ata.clone(),
+            &self.token_program,
+            amount_in,
+        )?;
+
+        // Wrap native SOL from the pool into the user's wrapped SOL account
+        sol_transfer_with_signer(
+            self.global_vault.clone(),
+            user_wsol_account.to_account_info(),
+            &self.system_program,
+            signer_seeds,
+            amount_out,
+        )?;
+        token::sync_native(CpiContext::new(
+            self.token_program.to_account_info(),
+            token::SyncNative {
+                account: user_wsol_account.to_account_info(),
+            },
+        ))?;
+    } else { // Buying Tokens with wrapped SOL
+        // Transfer tokens from pool to user
+        token_transfer_with_signer(
+            self.global_ata.clone(),
+            self.global_vault.clone(),
+            self.user_token_account.to_account_info(),
+            &self.token_program,
+            signer_seeds,
+            amount_out,
+        )?;
+
+        // Transfer wrapped SOL from user to pool, fee included
+        token_transfer_user(
+            user_wsol_account.to_account_info(),
+            &self.user,
+            global_wsol_account.to_account_info(),
+            &self.token_program,
+            amount_in,
+        )?;
+
+        // Unwrap it into the pool
+        unwrap_global_wsol(
+            global_wsol_account.to_account_info(),
+            self.global_vault.clone(),
+            native_mint.to_account_info(),
+            &self.token_program,
+            associated_token_program,
+            &self.system_program,
+            signer_seeds,
+        )?;
+    }
+
+    log_swap(
+        &self.token_mint.key(),
+        self.user.key,
+        direction,
+        amount_in,
+        amount_out,
+        bonding_curve.reserve_token,
+        bonding_curve.reserve_lamport,
+    );
+    Ok(amount_out)
+}
+
+}
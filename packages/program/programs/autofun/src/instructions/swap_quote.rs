@@ -0,0 +1,201 @@
+use anchor_lang::{system_program, prelude::*};
+use anchor_spl::token::{self, Mint, Token, TokenAccount};
+use crate::{
+    constants::{BONDING_CURVE, FEE_CONFIG, FEE_VAULT, GLOBAL, TRADER_RECORD},
+    errors::PumpfunError,
+    state::{BondingCurve, BondingCurveAccount, FeeConfig, Direction, FeeVault, SwapAmounts, TraderRecord},
+    utils::{log_swap, token_transfer_user, token_transfer_with_signer},
+};
+
+//  Swap against a curve quoted in an SPL mint
+//  quote amounts move between the user and the quote vault through the token program
+#[derive(Accounts)]
+pub struct SwapQuote<'info> {
+    //  bumps are read from stored fields instead of re-derived
+    #[account(
+        seeds = [FEE_CONFIG.as_bytes()],
+        bump = fee_config.load()?.bump,
+    )]
+    fee_config: AccountLoader<'info, FeeConfig>,
+
+    #[account(
+        mut,
+        seeds = [BONDING_CURVE.as_bytes(), &token_mint.key().to_bytes()], 
+        bump = bonding_curve.load()?.bump,
+        constraint = bonding_curve.load()?.quote_mint == quote_mint.key() @PumpfunError::IncorrectQuoteMint
+    )]
+    bonding_curve: AccountLoader<'info, BondingCurve>,
+
+    //  accrues the swap fee, swept to the team wallet by sweep_fees
+    #[account(
+        mut,
+        seeds = [FEE_VAULT.as_bytes(), quote_mint.key().as_ref()],
+        bump = fee_vault.bump
+    )]
+    fee_vault: Box<Account<'info, FeeVault>>,
+
+    //  created on the user's first trade on this curve, counts unique traders
+    #[account(
+        init_if_needed,
+        payer = user,
+        space = 8 + TraderRecord::INIT_SPACE,
+        seeds = [TRADER_RECORD.as_bytes(), bonding_curve.key().as_ref(), user.key().as_ref()],
+        bump
+    )]
+    trader_record: Box<Account<'info, TraderRecord>>,
+
+    /// CHECK: global vault pda which owns the curve token and quote vaults
+    #[account(
+        seeds = [GLOBAL.as_bytes()],
+        bump = fee_config.load()?.global_vault_bump,
+    )]
+    pub global_vault: AccountInfo<'info>,
+
+    pub token_mint: Box<Account<'info, Mint>>,
+
+    /// CHECK: ata of global vault
+    #[account(
+        mut,
+        seeds = [
+            global_vault.key().as_ref(),
+            anchor_spl::token::spl_token::ID.as_ref(),
+            token_mint.key().as_ref(),
+        ],
+        bump = bonding_curve.load()?.global_ata_bump,
+        seeds::program = anchor_spl::associated_token::ID
+    )]
+    global_ata: AccountInfo<'info>,
+
+    //  any token account of the user for the mint, not only the ata
+    #[account(
+        mut,
+        token::mint = token_mint,
+        token::authority = user
+    )]
+    user_token_account: Box<Account<'info, TokenAccount>>,
+
+    pub quote_mint: Box<Account<'info, Mint>>,
+
+    //  quote reserves of all curves in this quote mint
+    #[account(
+        mut,
+        associated_token::mint = quote_mint,
+        associated_token::authority = global_vault
+    )]
+    quote_vault: Box<Account<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        token::mint = quote_mint,
+        token::authority = user
+    )]
+    user_quote_account: Box<Account<'info, TokenAccount>>,
+
+    #[account(mut)]
+    pub user: Signer<'info>,
+
+    #[account(address = system_program::ID)]
+    pub system_program: Program<'info, System>,
+
+    #[account(address = token::ID)]
+    pub token_program: Program<'info, Token>,
+}
+
+impl<'info> SwapQuote<'info> {
+    pub fn process(&mut self, amount: u64, direction: u8, minimum_receive_amount: u64, deadline: i64) -> Result<u64> {
+        let direction = Direction::try_from(direction)?;
+
+        // Check deadline hasn't passed
+        let current_timestamp = Clock::get()?.unix_timestamp;
+        require!(
+            current_timestamp <= deadline,
+            PumpfunError::TransactionExpired
+        );
+
+        let bonding_curve_key = self.bonding_curve.key();
+        let mut bonding_curve = self.bonding_curve.load_mut()?;
+        let fee_config = self.fee_config.load()?;
+
+        //  check curve is not completed
+        require!(
+            !bonding_curve.is_completed(),
+            PumpfunError::CurveAlreadyCompleted
+        );
+
+        //  check trading has started for scheduled launches
+        require!(
+            current_timestamp >= bonding_curve.trading_starts_at,
+            PumpfunError::TradingNotStarted
+        );
+
+        let SwapAmounts { amount_in, amount_out, fee: _ } = bonding_curve.apply_swap(
+            &bonding_curve_key,
+            &fee_config,
+            &self.token_mint,
+            &mut self.fee_vault,
+            &mut self.trader_record,
+            amount,
+            direction,
+            minimum_receive_amount,
+            deadline,
+            self.user.key,
+        )?;
+        bonding_curve.log_completion();
+
+        let signer_seeds: &[&[&[u8]]] = &[&[
+            GLOBAL.as_bytes(),
+            &[fee_config.global_vault_bump],
+        ]];
+
+        if direction == Direction::Sell { // Selling Tokens for quote
+            // Transfer tokens from user to pool
+            token_transfer_user(
+                self.user_token_account.to_account_info(),
+                &self.user,
+                self.global_ata.clone(),
+                &self.token_program,
+                amount_in,
+            )?;
+
+            // Transfer net quote from pool to user
+            token_transfer_with_signer(
+                self.quote_vault.to_account_info(),
+                self.global_vault.clone(),
+                self.user_quote_account.to_account_info(),
+                &self.token_program,
+                signer_seeds,
+                amount_out,
+            )?;
+        } else { // Buying Tokens with quote
+            // Transfer tokens from pool to user
+            token_transfer_with_signer(
+                self.global_ata.clone(),
+                self.global_vault.clone(),
+                self.user_token_account.to_account_info(),
+                &self.token_program,
+                signer_seeds,
+                amount_out,
+            )?;
+
+            // Transfer quote from user to pool, fee included
+            token_transfer_user(
+                self.user_quote_account.to_account_info(),
+                &self.user,
+                self.quote_vault.to_account_info(),
+                &self.token_program,
+                amount_in,
+            )?;
+        }
+
+        log_swap(
+            &self.token_mint.key(),
+            self.user.key,
+            direction,
+            amount_in,
+            amount_out,
+            bonding_curve.reserve_token,
+            bonding_curve.reserve_lamport,
+        );
+        Ok(amount_out)
+    }
+}
@@ -0,0 +1,232 @@
+use anchor_lang::{system_program, prelude::*};
+use anchor_spl::token::{self, Mint, Token, TokenAccount};
+use crate::{
+    constants::{BONDING_CURVE, FEE_CONFIG, FEE_VAULT, GLOBAL, TRADER_RECORD},
+    errors::PumpfunError,
+    state::{BondingCurve, BondingCurveAccount, FeeConfig, Direction, FeeVault, SwapAmounts, TraderRecord},
+    utils::{sol_transfer_from_user, sol_transfer_with_signer, token_transfer_user, token_transfer_with_signer},
+};
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct SwapParams {
+    pub direction: Direction,
+    pub amount: u64, //  SOL in when buying, tokens in when selling
+    pub minimum_receive_amount: u64,
+    pub deadline: i64,
+    pub recipient: Option<Pubkey>, //  receives the output, defaults to the user
+    pub referrer: Option<Pubkey>, //  for attribution by indexers, read from the instruction data
+}
+
+//  returned to the caller through return data
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct SwapResult {
+    pub direction: Direction,
+    pub amount_in: u64,
+    pub amount_out: u64,
+    pub fee: u64,
+    pub reserve_token: u64,
+    pub reserve_lamport: u64,
+    pub is_completed: bool,
+}
+
+#[derive(Accounts)]
+#[instruction(params: SwapParams)]
+pub struct SwapV2<'info> {
+    //  bumps are read from stored fields instead of re-derived
+    #[account(
+        seeds = [FEE_CONFIG.as_bytes()],
+        bump = fee_config.load()?.bump,
+    )]
+    fee_config: AccountLoader<'info, FeeConfig>,
+
+    #[account(
+        mut,
+        seeds = [BONDING_CURVE.as_bytes(), &token_mint.key().to_bytes()], 
+        bump = bonding_curve.load()?.bump
+    )]
+    bonding_curve: AccountLoader<'info, BondingCurve>,
+
+    //  accrues the swap fee, swept to the team wallet by sweep_fees
+    #[account(
+        mut,
+        seeds = [FEE_VAULT.as_bytes(), bonding_curve.load()?.quote_mint.as_ref()],
+        bump = fee_vault.bump
+    )]
+    fee_vault: Box<Account<'info, FeeVault>>,
+
+    //  created on the user's first trade on this curve, counts unique traders
+    #[account(
+        init_if_needed,
+        payer = user,
+        space = 8 + TraderRecord::INIT_SPACE,
+        seeds = [TRADER_RECORD.as_bytes(), bonding_curve.key().as_ref(), user.key().as_ref()],
+        bump
+    )]
+    trader_record: Box<Account<'info, TraderRecord>>,
+
+    /// CHECK: global vault pda which stores SOL
+    #[account(
+        mut,
+        seeds = [GLOBAL.as_bytes()],
+        bump = fee_config.load()?.global_vault_bump,
+    )]
+    pub global_vault: AccountInfo<'info>,
+
+    pub token_mint: Box<Account<'info, Mint>>,
+
+    /// CHECK: ata of global vault
+    #[account(
+        mut,
+        seeds = [
+            global_vault.key().as_ref(),
+            anchor_spl::token::spl_token::ID.as_ref(),
+            token_mint.key().as_ref(),
+        ],
+        bump = bonding_curve.load()?.global_ata_bump,
+        seeds::program = anchor_spl::associated_token::ID
+    )]
+    global_ata: AccountInfo<'info>,
+
+    //  tokens are paid from this account when selling
+    #[account(
+        mut,
+        token::mint = token_mint,
+        token::authority = user
+    )]
+    user_token_account: Box<Account<'info, TokenAccount>>,
+
+    /// CHECK: receives SOL when selling, params.recipient or the user
+    #[account(
+        mut,
+        constraint = recipient.key() == params.recipient.unwrap_or(user.key()) @PumpfunError::IncorrectRecipient
+    )]
+    pub recipient: AccountInfo<'info>,
+
+    //  receives tokens when buying
+    #[account(
+        mut,
+        token::mint = token_mint,
+        token::authority = recipient
+    )]
+    recipient_token_account: Box<Account<'info, TokenAccount>>,
+
+    #[account(mut)]
+    pub user: Signer<'info>,
+
+    #[account(address = system_program::ID)]
+    pub system_program: Program<'info, System>,
+
+    #[account(address = token::ID)]
+    pub token_program: Program<'info, Token>,
+}
+
+impl<'info> SwapV2<'info> {
+    pub fn process(&mut self, params: SwapParams) -> Result<SwapResult> {
+        let SwapParams {
+            direction,
+            amount,
+            minimum_receive_amount,
+            deadline,
+            recipient: _,
+            referrer: _,
+        } = params;
+
+        // Check deadline hasn't passed
+        let current_timestamp = Clock::get()?.unix_timestamp;
+        require!(
+            current_timestamp <= deadline,
+            PumpfunError::TransactionExpired
+        );
+
+        let bonding_curve_key = self.bonding_curve.key();
+        let mut bonding_curve = self.bonding_curve.load_mut()?;
+        let fee_config = self.fee_config.load()?;
+
+        //  check curve is not completed
+        require!(
+            !bonding_curve.is_completed(),
+            PumpfunError::CurveAlreadyCompleted
+        );
+
+        //  curves quoted in an SPL mint trade through swap_quote
+        require!(
+            bonding_curve.is_native_quote(),
+            PumpfunError::IncorrectQuoteMint
+        );
+
+        //  check trading has started for scheduled launches
+        require!(
+            current_timestamp >= bonding_curve.trading_starts_at,
+            PumpfunError::TradingNotStarted
+        );
+
+        let SwapAmounts { amount_in, amount_out, fee } = bonding_curve.apply_swap(
+            &bonding_curve_key,
+            &fee_config,
+            &self.token_mint,
+            &mut self.fee_vault,
+            &mut self.trader_record,
+            amount,
+            direction,
+            minimum_receive_amount,
+            deadline,
+            self.user.key,
+        )?;
+
+        let signer_seeds: &[&[&[u8]]] = &[&[
+            GLOBAL.as_bytes(),
+            &[fee_config.global_vault_bump],
+        ]];
+
+        match direction {
+            Direction::Sell => {
+                // Transfer tokens from user to pool
+                token_transfer_user(
+                    self.user_token_account.to_account_info(),
+                    &self.user,
+                    self.global_ata.clone(),
+                    &self.token_program,
+                    amount_in,
+                )?;
+
+                // Transfer net SOL from pool to recipient
+                sol_transfer_with_signer(
+                    self.global_vault.clone(),
+                    self.recipient.clone(),
+                    &self.system_program,
+                    signer_seeds,
+                    amount_out,
+                )?;
+            }
+            Direction::Buy => {
+                // Transfer tokens from pool to recipient
+                token_transfer_with_signer(
+                    self.global_ata.clone(),
+                    self.global_vault.clone(),
+                    self.recipient_token_account.to_account_info(),
+                    &self.token_program,
+                    signer_seeds,
+                    amount_out,
+                )?;
+
+                // Transfer SOL from user to pool, fee included
+                sol_transfer_from_user(
+                    &self.user,
+                    self.global_vault.clone(),
+                    &self.system_program,
+                    amount_in,
+                )?;
+            }
+        }
+
+        Ok(SwapResult {
+            direction,
+            amount_in,
+            amount_out,
+            fee,
+            reserve_token: bonding_curve.reserve_token,
+            reserve_lamport: bonding_curve.reserve_lamport,
+            is_completed: bonding_curve.is_completed(),
+        })
+    }
+}
@@ -0,0 +1,116 @@
+use crate::{
+    constants::{CONFIG, FEE_VAULT, GLOBAL},
+    errors::*,
+    events::FeesSwept,
+    state::{Config, FeeVault},
+    utils::{sol_transfer_with_signer, token_transfer_with_signer},
+};
+use anchor_lang::{prelude::*, system_program};
+use anchor_spl::token::{self, Token, TokenAccount};
+
+//  Pays the fees accrued by swaps to the team wallet, callable by anyone
+#[derive(Accounts)]
+pub struct SweepFees<'info> {
+    #[account(
+        seeds = [CONFIG.as_bytes()],
+        bump,
+    )]
+    global_config: Box<Account<'info, Config>>,
+
+    #[account(
+        mut,
+        seeds = [FEE_VAULT.as_bytes(), fee_vault.quote_mint.as_ref()],
+        bump
+    )]
+    fee_vault: Box<Account<'info, FeeVault>>,
+
+    /// CHECK: global vault pda which stores SOL
+    #[account(
+        mut,
+        seeds = [GLOBAL.as_bytes()],
+        bump,
+    )]
+    pub global_vault: AccountInfo<'info>,
+
+    //  team wallet
+    /// CHECK: should be same with the address in the global_config
+    #[account(
+        mut,
+        constraint = global_config.team_wallet == team_wallet.key() @PumpfunError::IncorrectAuthority
+    )]
+    pub team_wallet: AccountInfo<'info>,
+
+    //  required for an SPL quote fee vault
+    #[account(
+        mut,
+        associated_token::mint = fee_vault.quote_mint,
+        associated_token::authority = global_vault
+    )]
+    quote_vault: Option<Box<Account<'info, TokenAccount>>>,
+
+    //  required for an SPL quote fee vault
+    #[account(
+        mut,
+        token::mint = fee_vault.quote_mint,
+        token::authority = team_wallet
+    )]
+    team_wallet_quote_account: Option<Box<Account<'info, TokenAccount>>>,
+
+    #[account(address = system_program::ID)]
+    system_program: Program<'info, System>,
+
+    #[account(address = token::ID)]
+    token_program: Program<'info, Token>,
+}
+
+impl<'info> SweepFees<'info> {
+    pub fn process(&mut self, global_vault_bump: u8) -> Result<()> {
+        let fee_vault = &mut self.fee_vault;
+        let amount = fee_vault.accrued_fees;
+
+        if amount == 0 {
+            return Ok(());
+        }
+
+        let signer_seeds: &[&[&[u8]]] = &[&[GLOBAL.as_bytes(), &[global_vault_bump]]];
+
+        if fee_vault.quote_mint == Pubkey::default() {
+            sol_transfer_with_signer(
+                self.global_vault.clone(),
+                self.team_wallet.clone(),
+                &self.system_program,
+                signer_seeds,
+                amount,
+            )?;
+        } else {
+            let (Some(quote_vault), Some(team_wallet_quote_account)) =
+                (&self.quote_vault, &self.team_wallet_quote_account)
+            else {
+                return err!(PumpfunError::IncorrectQuoteMint);
+            };
+
+            token_transfer_with_signer(
+                quote_vault.to_account_info(),
+                self.global_vault.clone(),
+                team_wallet_quote_account.to_account_info(),
+                &self.token_program,
+                signer_seeds,
+                amount,
+            )?;
+        }
+
+        fee_vault.accrued_fees = 0;
+        fee_vault.total_swept = fee_vault
+            .total_swept
+            .checked_add(amount)
+            .ok_or(PumpfunError::OverflowOrUnderflowOccurred)?;
+
+        emit!(FeesSwept {
+            quote_mint: fee_vault.quote_mint,
+            team_wallet: self.team_wallet.key(),
+            amount,
+        });
+
+        Ok(())
+    }
+}
@@ -0,0 +1,73 @@
+use crate::{
+    constants::{CONFIG, GLOBAL},
+    errors::*,
+    state::Config,
+    utils::unwrap_global_wsol,
+};
+use anchor_lang::{prelude::*, system_program};
+use anchor_spl::{
+    associated_token::{self, AssociatedToken},
+    token::{self, Mint, Token, TokenAccount},
+};
+
+//  Swaps unwrap the wrapped SOL they take in right away, this moves anything else sent
+//  to global_wsol_account back to the global vault as native SOL
+//  the account is opened again, so wrapped SOL swaps keep working
+#[derive(Accounts)]
+pub struct UnwrapVaultWsol<'info> {
+    #[account(
+        constraint = global_config.authority == admin.key() @PumpfunError::IncorrectAuthority
+    )]
+    admin: Signer<'info>,
+
+    #[account(
+        seeds = [CONFIG.as_bytes()],
+        bump,
+    )]
+    global_config: Box<Account<'info, Config>>,
+
+    /// CHECK: global vault pda which stores SOL
+    #[account(
+        mut,
+        seeds = [GLOBAL.as_bytes()],
+        bump,
+    )]
+    pub global_vault: AccountInfo<'info>,
+
+    #[account(
+        mut,
+        associated_token::mint = native_mint,
+        associated_token::authority = global_vault
+    )]
+    global_wsol_account: Box<Account<'info, TokenAccount>>,
+
+    #[account(address = spl_token::native_mint::ID)]
+    native_mint: Box<Account<'info, Mint>>,
+
+    #[account(address = system_program::ID)]
+    system_program: Program<'info, System>,
+
+    #[account(address = token::ID)]
+    token_program: Program<'info, Token>,
+
+    #[account(address = associated_token::ID)]
+    associated_token_program: Program<'info, AssociatedToken>,
+}
+
+impl<'info> UnwrapVaultWsol<'info> {
+    pub fn process(&mut self, global_vault_bump: u8) -> Result<()> {
+        let signer_seeds: &[&[&[u8]]] = &[&[GLOBAL.as_bytes(), &[global_vault_bump]]];
+
+        msg!("unwrap lamports: {:?}", self.global_wsol_account.amount);
+
+        unwrap_global_wsol(
+            self.global_wsol_account.to_account_info(),
+            self.global_vault.clone(),
+            self.native_mint.to_account_info(),
+            &self.token_program,
+            &self.associated_token_program,
+            &self.system_program,
+            signer_seeds,
+        )
+    }
+}
@@ -7,10 +7,11 @@ use anchor_spl::{
     token::{self, Mint, Token, TokenAccount},
 };
 
+//  Moves a completed curve's liquidity to the admin, who migrates it from the backend
+//  admin only, a keeper bounty would pay anyone to trigger this transfer while the migration stays off-chain
 #[derive(Accounts)]
 pub struct Withdraw<'info> {
     #[account(
-        mut,
         seeds = [CONFIG.as_bytes()],
         bump,
     )]
@@ -37,7 +38,7 @@ pub struct Withdraw<'info> {
         seeds = [BONDING_CURVE.as_bytes(), &token_mint.key().to_bytes()], 
         bump
     )]
-    bonding_curve: Box<Account<'info, BondingCurve>>,
+    bonding_curve: AccountLoader<'info, BondingCurve>,
 
     /// CHECK: ata of global vault
     #[account(
@@ -80,11 +81,13 @@ pub fn process(
     &mut self,
     global_vault_bump:u8,
 ) -> Result<()> {
-    let bonding_curve = &mut self.bonding_curve;
-    let global_config = &mut self.global_config;
+    let mut bonding_curve = self.bonding_curve.load_mut()?;
     let admin_ata = &mut self.admin_ata;
 
-    require!(bonding_curve.is_completed, PumpfunError::CurveNotCompleted);
+    require!(bonding_curve.is_completed(), PumpfunError::CurveNotCompleted);
+
+    //  curves quoted in an SPL mint are withdrawn through withdraw_quote
+    require!(bonding_curve.is_native_quote(), PumpfunError::IncorrectQuoteMint);
 
     //  create admin wallet ata, if it doesn't exist
     if admin_ata.data_is_empty() {
@@ -103,7 +106,10 @@ pub fn process(
     }
 
     // transfer sol/token to admin wallet
-    let lamport_amount = bonding_curve.reserve_lamport - bonding_curve.init_lamport;
+    let lamport_amount = bonding_curve
+        .reserve_lamport
+        .checked_sub(bonding_curve.init_lamport)
+        .ok_or(PumpfunError::OverflowOrUnderflowOccurred)?;
     let signer_seeds: &[&[&[u8]]] = &[&[
         GLOBAL.as_bytes(),
         &[global_vault_bump],
@@ -134,7 +140,7 @@ pub fn process(
         bonding_curve.reserve_token,
     )?;
 
-    bonding_curve.update_reserves(global_config, 0, 0)?;
+    bonding_curve.update_reserves(0, 0)?;
 
     Ok(())
 }
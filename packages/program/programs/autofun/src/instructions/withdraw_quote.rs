@@ -0,0 +1,157 @@
+use crate::{
+    constants::{BONDING_CURVE, CONFIG, GLOBAL}, errors::*, state::{BondingCurve, BondingCurveAccount, Config}, utils::token_transfer_with_signer
+};
+use anchor_lang::{prelude::*, system_program};
+use anchor_spl::{
+    associated_token::{self, AssociatedToken}, 
+    token::{self, Mint, Token, TokenAccount},
+};
+
+//  Same as Withdraw, for curves quoted in an SPL mint
+#[derive(Accounts)]
+pub struct WithdrawQuote<'info> {
+    #[account(
+        seeds = [CONFIG.as_bytes()],
+        bump,
+    )]
+    global_config: Box<Account<'info, Config>>,
+
+    /// CHECK: global vault pda which owns the curve token and quote vaults
+    #[account(
+        seeds = [GLOBAL.as_bytes()],
+        bump,
+    )]
+    pub global_vault: AccountInfo<'info>,
+    
+    #[account(
+        mut,
+        constraint = global_config.authority == admin.key() @PumpfunError::IncorrectAuthority
+    )]
+    admin: Signer<'info>,
+
+    token_mint: Box<Account<'info, Mint>>,
+
+    #[account(
+        mut,
+        seeds = [BONDING_CURVE.as_bytes(), &token_mint.key().to_bytes()], 
+        bump,
+        constraint = bonding_curve.load()?.quote_mint == quote_mint.key() @PumpfunError::IncorrectQuoteMint
+    )]
+    bonding_curve: AccountLoader<'info, BondingCurve>,
+
+    /// CHECK: ata of global vault
+    #[account(
+        mut,
+        seeds = [
+            global_vault.key().as_ref(),
+            anchor_spl::token::spl_token::ID.as_ref(),
+            token_mint.key().as_ref(),
+        ],
+        bump,
+        seeds::program = anchor_spl::associated_token::ID
+    )]
+    global_vault_ata: AccountInfo<'info>,
+
+    /// CHECK: ata of admin
+    #[account(
+        mut,
+        seeds = [
+            admin.key().as_ref(),
+            anchor_spl::token::spl_token::ID.as_ref(),
+            token_mint.key().as_ref(),
+        ],
+        bump,
+        seeds::program = anchor_spl::associated_token::ID
+    )]
+    admin_ata: AccountInfo<'info>,
+
+    quote_mint: Box<Account<'info, Mint>>,
+
+    #[account(
+        mut,
+        associated_token::mint = quote_mint,
+        associated_token::authority = global_vault
+    )]
+    quote_vault: Box<Account<'info, TokenAccount>>,
+
+    #[account(
+        init_if_needed,
+        payer = admin,
+        associated_token::mint = quote_mint,
+        associated_token::authority = admin
+    )]
+    admin_quote_account: Box<Account<'info, TokenAccount>>,
+
+    #[account(address = system_program::ID)]
+    system_program: Program<'info, System>,
+
+    #[account(address = token::ID)]
+    token_program: Program<'info, Token>,
+
+    #[account(address = associated_token::ID)]
+    associated_token_program: Program<'info, AssociatedToken>,
+}
+
+impl<'info> WithdrawQuote<'info> {
+pub fn process(
+    &mut self,
+    global_vault_bump:u8,
+) -> Result<()> {
+    let mut bonding_curve = self.bonding_curve.load_mut()?;
+    let admin_ata = &mut self.admin_ata;
+
+    require!(bonding_curve.is_completed(), PumpfunError::CurveNotCompleted);
+
+    //  create admin wallet ata, if it doesn't exist
+    if admin_ata.data_is_empty() {
+        anchor_spl::associated_token::create(CpiContext::new(
+            self.associated_token_program.to_account_info(),
+            anchor_spl::associated_token::Create {
+                payer: self.admin.to_account_info(),
+                associated_token: admin_ata.to_account_info(),
+                authority: self.admin.to_account_info(),
+
+                mint: self.token_mint.to_account_info(),
+                system_program: self.system_program.to_account_info(),
+                token_program: self.token_program.to_account_info(),
+            }
+        ))?;
+    }
+
+    // transfer quote/token to admin wallet
+    let quote_amount = bonding_curve
+        .reserve_lamport
+        .checked_sub(bonding_curve.init_lamport)
+        .ok_or(PumpfunError::OverflowOrUnderflowOccurred)?;
+    let signer_seeds: &[&[&[u8]]] = &[&[
+        GLOBAL.as_bytes(),
+        &[global_vault_bump],
+    ]];
+
+    msg!("withdraw quote: {:?}", quote_amount);
+    msg!("withdraw token: {:?}", bonding_curve.reserve_token);
+
+    token_transfer_with_signer(
+        self.quote_vault.to_account_info(),
+        self.global_vault.clone(),
+        self.admin_quote_account.to_account_info(),
+        &self.token_program,
+        signer_seeds,
+        quote_amount,
+    )?;
+
+    token_transfer_with_signer(
+        self.global_vault_ata.clone(),
+        self.global_vault.clone(),
+        self.admin_ata.clone(),
+        &self.token_program,
+        signer_seeds,
+        bonding_curve.reserve_token,
+    )?;
+
+    bonding_curve.update_reserves(0, 0)?;
+
+    Ok(())
+}
+
+}
@@ -7,7 +7,7 @@ pub mod utils;
 
 use crate::instructions::*;
 use anchor_lang::prelude::*;
-use state::Config;
+use state::{Config, QuoteConfig};
 // use crate::errors::PumpfunError;
 
 declare_id!("autoUmixaMaYKFjexMpQuBpNYntgbkzCo2b1ZqUaAZ5");
@@ -18,8 +18,43 @@ pub mod autofun {
 
     //  called by admin to set global config
     //  need to check the signer is authority
-    pub fn configure(ctx: Context<Configure>, new_config: Config) -> Result<()> {
-        ctx.accounts.process(new_config, ctx.bumps.config)
+    //  platform fees are in basis points
+    pub fn configure(
+        ctx: Context<Configure>,
+        new_config: Config,
+        platform_buy_fee: u64,
+        platform_sell_fee: u64
+    ) -> Result<()> {
+        ctx.accounts.process(
+            new_config,
+            platform_buy_fee,
+            platform_sell_fee,
+            ctx.bumps.config,
+            ctx.bumps.fee_config,
+            ctx.bumps.fee_vault,
+            ctx.bumps.global_vault
+        )
+    }
+
+    //  called by admin once to move a legacy config's swap fees to fee_config
+    pub fn migrate_config(ctx: Context<MigrateConfig>) -> Result<()> {
+        ctx.accounts.process(
+            ctx.bumps.fee_config,
+            ctx.bumps.fee_vault,
+            ctx.bumps.global_vault
+        )
+    }
+
+    //  called by admin to whitelist an SPL quote mint and set its launch config
+    pub fn configure_quote(
+        ctx: Context<ConfigureQuote>,
+        new_quote_config: QuoteConfig
+    ) -> Result<()> {
+        ctx.accounts.process(
+            new_quote_config,
+            ctx.bumps.quote_config,
+            ctx.bumps.fee_vault
+        )
     }
 
     //  Admin can hand over admin role
@@ -32,32 +67,29 @@ pub mod autofun {
         ctx.accounts.process()
     }
 
-    pub fn launch(
-        ctx: Context<Launch>,
+    pub fn launch(ctx: Context<Launch>, params: LaunchParams) -> Result<()> {
+        ctx.accounts.process(params, &ctx.bumps)
+    }
 
-        // launch config
-        decimals: u8,
-        token_supply: u64,
-        virtual_lamport_reserves: u64,
+    //  grows a curve launched with the original layout to the current one, callable by anyone
+    pub fn migrate_bonding_curve(ctx: Context<MigrateBondingCurve>) -> Result<()> {
+        ctx.accounts.process(ctx.bumps.bonding_curve)
+    }
 
-        //  metadata
-        name: String,
-        symbol: String,
-        uri: String
+    //  launch with a mint PDA derived from the creator and nonce
+    //  nonce - the creator's current launch count
+    pub fn launch_with_nonce(
+        ctx: Context<LaunchWithNonce>,
+        nonce: u64,
+        params: LaunchParams
     ) -> Result<()> {
-        ctx.accounts.process(
-            decimals,
-            token_supply,
-            virtual_lamport_reserves,
-            name,
-            symbol,
-            uri,
-            ctx.bumps.global_vault
-        )
+        ctx.accounts.process(nonce, params, &ctx.bumps)
     }
 
     //  amount - swap amount
     //  direction - 0: buy, 1: sell
+    //  pass user_wsol_account, global_wsol_account, native_mint and associated_token_program
+    //  to pay or receive wrapped SOL
     pub fn swap(
         ctx: Context<Swap>,
         amount: u64,
@@ -69,44 +101,120 @@ pub mod autofun {
             amount,
             direction,
             minimum_receive_amount,
-            deadline,
-            ctx.bumps.global_vault
+            deadline
+        )
+    }
+
+    //  typed swap, returns the SwapResult as return data
+    //  writes no logs, only CompleteEvent on the trade that completes the curve
+    //  budgeted at SWAP_V2_COMPUTE_BUDGET compute units, including a first trade and a completing trade
+    pub fn swap_v2(ctx: Context<SwapV2>, params: SwapParams) -> Result<SwapResult> {
+        ctx.accounts.process(params)
+    }
+
+    //  swap against a curve quoted in an SPL mint
+    //  amount - swap amount, in quote units when buying
+    //  direction - 0: buy, 1: sell
+    pub fn swap_quote(
+        ctx: Context<SwapQuote>,
+        amount: u64,
+        direction: u8,
+        minimum_receive_amount: u64,
+        deadline: i64
+    ) -> Result<u64> {
+        ctx.accounts.process(
+            amount,
+            direction,
+            minimum_receive_amount,
+            deadline
         )
     }
 
     // Combined launch and swap instruction for initial buy on token launch
+    //  the initial buy is exempt from params.trading_starts_at
     pub fn launch_and_swap(
         ctx: Context<LaunchAndSwap>,
-        // launch config
-        decimals: u8,
-        token_supply: u64,
-        virtual_lamport_reserves: u64,
-        // metadata
-        name: String,
-        symbol: String,
-        uri: String,
+        params: LaunchParams,
         // swap config
         swap_amount: u64,
         minimum_receive_amount: u64,
         deadline: i64
     ) -> Result<u64> {
         ctx.accounts.process(
-            decimals,
-            token_supply,
-            virtual_lamport_reserves,
-            name,
-            symbol,
-            uri,
+            params,
             swap_amount,
             minimum_receive_amount,
             deadline,
-            ctx.bumps.global_vault
+            &ctx.bumps
         )
     }
 
-    //  admin withdraws token & sol
-    //  migration should be done on backend
+    //  admin withdraws token & sol of a completed curve
+    //  migration should be done on backend, so there is no permissionless keeper bounty for it
     pub fn withdraw(ctx: Context<Withdraw>) -> Result<()> {
         ctx.accounts.process(ctx.bumps.global_vault)
     }
+
+    //  pays accrued swap fees to the team wallet, callable by anyone
+    pub fn sweep_fees(ctx: Context<SweepFees>) -> Result<()> {
+        ctx.accounts.process(ctx.bumps.global_vault)
+    }
+
+    //  admin moves wrapped SOL sent to the global wrapped SOL account back to the global vault
+    pub fn unwrap_vault_wsol(ctx: Context<UnwrapVaultWsol>) -> Result<()> {
+        ctx.accounts.process(ctx.bumps.global_vault)
+    }
+
+    //  view, time weighted average price over the last window seconds
+    //  in quote base units per token base unit, scaled by PRICE_SCALE
+    //  one observation is kept per TWAP_OBSERVATION_INTERVAL, windows older than the oldest one fail
+    pub fn get_twap(ctx: Context<GetTwap>, window: u32) -> Result<u64> {
+        ctx.accounts.process(window)
+    }
+
+    //  holder burns tokens of an expired curve for a pro-rata share of the raised SOL
+    pub fn refund(ctx: Context<Refund>, amount: u64) -> Result<()> {
+        ctx.accounts.process(amount, ctx.bumps.global_vault)
+    }
+
+    //  creator opens a batch auction on a scheduled launch, bids clear at one price
+    pub fn open_auction(ctx: Context<OpenAuction>, ends_at: i64) -> Result<()> {
+        ctx.accounts.process(ends_at, ctx.bumps.auction)
+    }
+
+    pub fn place_bid(ctx: Context<PlaceBid>, amount: u64) -> Result<()> {
+        ctx.accounts.process(amount)
+    }
+
+    //  anyone settles an auction after its commit window
+    pub fn settle_auction(ctx: Context<SettleAuction>) -> Result<()> {
+        ctx.accounts.process()
+    }
+
+    pub fn claim_auction(ctx: Context<ClaimAuction>) -> Result<()> {
+        ctx.accounts.process(ctx.bumps.global_vault)
+    }
+
+    //  limit orders, escrowed on placement and filled by any keeper for the order's tip
+    pub fn place_order(ctx: Context<PlaceOrder>, nonce: u64, params: OrderParams) -> Result<()> {
+        ctx.accounts.process(nonce, params, ctx.bumps.order)
+    }
+
+    pub fn fill_order(ctx: Context<FillOrder>) -> Result<()> {
+        ctx.accounts.process()
+    }
+
+    pub fn cancel_order(ctx: Context<CancelOrder>) -> Result<()> {
+        ctx.accounts.process()
+    }
+
+    //  anyone returns a trader record's rent to the trader once its curve is completed or expired
+    pub fn close_trader_record(ctx: Context<CloseTraderRecord>) -> Result<()> {
+        ctx.accounts.process()
+    }
+
+    //  admin withdraws token & quote of a curve quoted in an SPL mint
+    pub fn withdraw_quote(ctx: Context<WithdrawQuote>) -> Result<()> {
+        ctx.accounts.process(ctx.bumps.global_vault)
+    }
 }
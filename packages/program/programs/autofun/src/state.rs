@@ -1,4 +1,4 @@
-use crate::constants::LAMPORT_DECIMALS;
+use crate::constants::{LAMPORT_DECIMALS, TWAP_OBSERVATIONS, TWAP_OBSERVATION_INTERVAL};
 use crate::errors::*;
 use crate::events::CompleteEvent;
 use crate::utils::*;
@@ -9,6 +9,8 @@ use core::fmt::Debug;
 
 pub const FEE_BASIS_POINTS: u128 = 10000;
 pub const HUNDRED_PERCENT_BPS: u128 = 10000;
+//  twap prices are quote base units per token base unit, times PRICE_SCALE
+pub const PRICE_SCALE: u128 = 1_000_000_000_000;
 #[account]
 pub struct Config {
     pub authority: Pubkey,
@@ -19,9 +21,6 @@ pub struct Config {
 
     pub init_bonding_curve: f64, // bonding curve init percentage. The remaining amount is sent to team wallet for distribution to agent
 
-    pub platform_buy_fee: u128, //  platform fee percentage
-    pub platform_sell_fee: u128,
-
     pub curve_limit: u64, //  lamports to complete the bonding curve
 
     pub lamport_amount_config: AmountConfig<u64>,
@@ -66,8 +65,8 @@ impl<T: PartialEq + PartialOrd + Debug> AmountConfig<T> {
     }
 }
 
-#[account]
-#[derive(InitSpace)]
+//  zero copy with a fixed layout, offsets are stable for indexers reading raw account data
+#[account(zero_copy)]
 pub struct BondingCurve {
     pub token_mint: Pubkey,
     pub creator: Pubkey,
@@ -75,28 +74,414 @@ pub struct BondingCurve {
     pub reserve_lamport: u64,
     pub reserve_token: u64,
     pub curve_limit: u64,  // Store curve limit at launch time
-    pub is_completed: bool,
+    pub is_completed: u8, // 0 or 1
+    pub padding0: [u8; 7],
+    pub trading_starts_at: i64, // swaps are rejected before this timestamp, 0 if trading opens at launch
+    pub quote_mint: Pubkey, // Pubkey::default() for SOL, otherwise reserves are in this SPL mint's base units
+    pub bump: u8,
+    pub global_ata_bump: u8, //  bump of the global vault's token ata, so swaps don't re-derive it
+    pub padding1: [u8; 6],
+
+    //  twap oracle, see record_price and twap
+    pub price_cumulative: u64, //  wrapping sum of price * seconds, only differences are meaningful
+    pub last_price: u64, //  spot price since last_update_ts
+    pub last_update_ts: i64,
+    pub observation_index: u8, //  slot of the latest observation
+    pub padding2: [u8; 7],
+    pub observations: [Observation; TWAP_OBSERVATIONS],
+
+    //  trading stats, volumes and fees are in quote base units
+    pub total_buy_volume_lamports: u64, //  paid in by buyers, fee included
+    pub total_sell_volume_lamports: u64, //  paid out to sellers, before the fee
+    pub trade_count: u64,
+    pub unique_trader_count: u64,
+    pub total_fees_paid: u64,
+    pub last_trade_ts: i64,
+
+    pub expires_at: i64, //  0 if the launch never expires
+    pub auction_ends_at: i64, //  commit window end of a pending batch auction, 0 once settled or if none
+
+    pub init_reserve_token: u64, //  reserve_token at launch, tokens bought off the curve are the difference
+    pub refunded_token: u64, //  burned by refunds after expiry
+}
+
+//  price_cumulative at a point in time, one per TWAP_OBSERVATION_INTERVAL at most
+#[zero_copy]
+pub struct Observation {
+    pub timestamp: i64,
+    pub price_cumulative: u64,
+}
+
+impl BondingCurve {
+    pub const SIZE: usize = std::mem::size_of::<Self>();
+
+    pub fn is_native_quote(&self) -> bool {
+        self.quote_mint == Pubkey::default()
+    }
+
+    pub fn is_completed(&self) -> bool {
+        self.is_completed != 0
+    }
+
+    //  completion log the indexer reads, written by every swap path but swap_v2,
+    //  which only emits CompleteEvent
+    pub fn log_completion(&self) {
+        if self.is_completed() {
+            msg!("curve is completed");
+        }
+    }
+
+    //  past its expiry without completing, trading stops and holders can refund
+    pub fn is_expired(&self, now: i64) -> bool {
+        self.expires_at != 0 && now >= self.expires_at && !self.is_completed()
+    }
+
+    //  SOL owed for burning `amount` tokens of an expired curve, a pro-rata share of the SOL raised
+    //  among the tokens bought off the curve and not refunded yet
+    //  refund caps each holder by their TraderRecord, so the team allocation gets none
+    pub fn refund_amount(&self, amount: u64) -> Result<u64> {
+        let outstanding = self
+            .init_reserve_token
+            .checked_sub(self.reserve_token)
+            .and_then(|bought| bought.checked_sub(self.refunded_token))
+            .ok_or(PumpfunError::OverflowOrUnderflowOccurred)?;
+        let raised = self
+            .reserve_lamport
+            .checked_sub(self.init_lamport)
+            .ok_or(PumpfunError::OverflowOrUnderflowOccurred)?;
+        require!(amount > 0 && amount <= outstanding, PumpfunError::InvalidAmount);
+
+        let refund_amount = (amount as u128)
+            .checked_mul(raised as u128)
+            .and_then(|value| value.checked_div(outstanding as u128))
+            .ok_or(PumpfunError::OverflowOrUnderflowOccurred)?;
+
+        Ok(refund_amount as u64)
+    }
+
+    pub fn has_pending_auction(&self) -> bool {
+        self.auction_ends_at != 0
+    }
+
+    pub fn spot_price(&self) -> u64 {
+        if self.reserve_token == 0 {
+            return 0;
+        }
+        (self.reserve_lamport as u128 * PRICE_SCALE / self.reserve_token as u128)
+            .min(u64::MAX as u128) as u64
+    }
+
+    //  accumulates the price held since the last update, then stores the current price
+    //  an observation is written to the ring buffer once per TWAP_OBSERVATION_INTERVAL,
+    //  trades in between only move the accumulator
+    pub fn record_price(&mut self, now: i64) {
+        let elapsed = now.saturating_sub(self.last_update_ts).max(0) as u64;
+        self.price_cumulative = self
+            .price_cumulative
+            .wrapping_add(self.last_price.wrapping_mul(elapsed));
+        self.last_price = self.spot_price();
+        self.last_update_ts = now;
+
+        let latest = &self.observations[self.observation_index as usize];
+        if latest.timestamp == 0 || now >= latest.timestamp.saturating_add(TWAP_OBSERVATION_INTERVAL) {
+            self.observation_index = ((self.observation_index as usize + 1) % TWAP_OBSERVATIONS) as u8;
+            self.observations[self.observation_index as usize] = Observation {
+                timestamp: now,
+                price_cumulative: self.price_cumulative,
+            };
+        }
+    }
+
+    //  time weighted average price over the last window seconds, scaled by PRICE_SCALE
+    //  errors with TwapWindowTooLong when the window starts before the oldest observation
+    pub fn twap(&self, now: i64, window: u32) -> Result<u64> {
+        require!(window > 0, PumpfunError::InvalidTwapWindow);
+
+        //  the accumulator itself is the newest point, observations lag it by up to an interval
+        let live = Observation {
+            timestamp: self.last_update_ts,
+            price_cumulative: self.price_cumulative,
+        };
+        let cumulative_at = |timestamp: i64| -> Option<u64> {
+            //  observation at or right before timestamp, and the one right after it
+            let recorded = self
+                .observations
+                .iter()
+                .chain(std::iter::once(&live))
+                .filter(|o| o.timestamp != 0);
+            let before = recorded
+                .clone()
+                .filter(|o| o.timestamp <= timestamp)
+                .max_by_key(|o| o.timestamp)?;
+            let after = recorded
+                .filter(|o| o.timestamp > timestamp)
+                .min_by_key(|o| o.timestamp);
+            let delta = (timestamp - before.timestamp) as u64;
+            Some(match after {
+                //  interpolate between the two observations
+                Some(after) => {
+                    let span = (after.timestamp - before.timestamp) as u128;
+                    let growth = after.price_cumulative.wrapping_sub(before.price_cumulative) as u128;
+                    before
+                        .price_cumulative
+                        .wrapping_add((growth * delta as u128 / span) as u64)
+                }
+                //  past the last update, the last price has held since
+                None => before
+                    .price_cumulative
+                    .wrapping_add(self.last_price.wrapping_mul(delta)),
+            })
+        };
+
+        let start = cumulative_at(now - window as i64).ok_or(PumpfunError::TwapWindowTooLong)?;
+        let end = cumulative_at(now).ok_or(PumpfunError::TwapWindowTooLong)?;
+
+        Ok(end.wrapping_sub(start) / window as u64)
+    }
+}
+
+//  launch settings for curves quoted in an SPL mint instead of SOL
+//  one account per whitelisted quote mint, set by the admin
+#[account]
+pub struct QuoteConfig {
+    pub quote_mint: Pubkey,
+    pub enabled: bool,
+
+    pub curve_limit: u64, //  quote amount to complete the bonding curve
+
+    pub reserve_amount_config: AmountConfig<u64>, //  allowed virtual quote reserves
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug, InitSpace)]
+pub enum Direction {
+    Buy,
+    Sell,
+}
+
+//  legacy swap encoding, 0: buy, 1: sell
+impl From<Direction> for u8 {
+    fn from(direction: Direction) -> Self {
+        match direction {
+            Direction::Buy => 0,
+            Direction::Sell => 1,
+        }
+    }
+}
+
+impl TryFrom<u8> for Direction {
+    type Error = Error;
+
+    fn try_from(direction: u8) -> Result<Self> {
+        match direction {
+            0 => Ok(Self::Buy),
+            1 => Ok(Self::Sell),
+            _ => err!(PumpfunError::InvalidDirection),
+        }
+    }
+}
+
+//  fees collected by swaps stay in the global vault (or quote vault) until swept
+//  one per quote mint, Pubkey::default() for SOL
+#[account]
+#[derive(InitSpace)]
+pub struct FeeVault {
+    pub quote_mint: Pubkey,
+    pub accrued_fees: u64, //  not yet swept to the team wallet
+    pub total_swept: u64,
+    pub bump: u8,
+}
+
+//  swap fees, kept out of Config so swaps read them without deserializing it
+//  zero copy with a fixed layout like BondingCurve
+#[account(zero_copy)]
+pub struct FeeConfig {
+    pub platform_buy_fee: u64, //  basis points
+    pub platform_sell_fee: u64,
+    pub bump: u8,
+    pub global_vault_bump: u8,
+    pub padding: [u8; 6],
+}
+
+impl FeeConfig {
+    pub const SIZE: usize = std::mem::size_of::<Self>();
+}
+
+impl FeeVault {
+    pub fn accrue(&mut self, fee: u64) -> Result<()> {
+        self.accrued_fees = self
+            .accrued_fees
+            .checked_add(fee)
+            .ok_or(PumpfunError::OverflowOrUnderflowOccurred)?;
+        Ok(())
+    }
+}
+
+//  created on a user's first trade on a curve, counts unique traders
+//  and caps the user's refunds if the curve expires
+#[account]
+#[derive(InitSpace)]
+pub struct TraderRecord {
+    pub bonding_curve: Pubkey,
+    pub user: Pubkey,
+    pub first_trade_ts: i64,
+    pub bought_token: u64, //  bought off the curve or won at auction, less sold and refunded
+}
+
+impl TraderRecord {
+    pub fn record_trade(&mut self, direction: Direction, amount_in: u64, amount_out: u64) {
+        self.bought_token = match direction {
+            Direction::Buy => self.bought_token.saturating_add(amount_out),
+            Direction::Sell => self.bought_token.saturating_sub(amount_in),
+        };
+    }
+
+    //  only tokens the trader bought can be refunded, so tokens minted to the team
+    //  at launch or received by transfer never draw on the raised SOL
+    pub fn take_refund(&mut self, amount: u64) -> Result<()> {
+        self.bought_token = self
+            .bought_token
+            .checked_sub(amount)
+            .ok_or(PumpfunError::RefundExceedsBought)?;
+        Ok(())
+    }
+}
+
+//  Batch auction fair launch, bids are escrowed in this account's lamports
+//  and cleared at settle_auction as a single buy at one uniform price
+#[account]
+#[derive(InitSpace)]
+pub struct Auction {
+    pub bonding_curve: Pubkey,
+    pub token_mint: Pubkey,
+    pub ends_at: i64,
+    pub total_bids: u64,
+    pub lamports_used: u64, //  bids spent on the curve, fee included, set at settlement
+    pub tokens_out: u64, //  tokens bought for all bidders, set at settlement
+    pub is_settled: bool,
+    pub bump: u8,
+}
+
+impl Auction {
+    //  tokens and unspent lamports of a bid, pro-rata to its share of all bids
+    pub fn allocation(&self, bid_amount: u64) -> Result<(u64, u64)> {
+        let share = |amount: u64| -> Result<u64> {
+            Ok((bid_amount as u128)
+                .checked_mul(amount as u128)
+                .and_then(|value| value.checked_div(self.total_bids as u128))
+                .ok_or(PumpfunError::OverflowOrUnderflowOccurred)? as u64)
+        };
+        let unspent = self
+            .total_bids
+            .checked_sub(self.lamports_used)
+            .ok_or(PumpfunError::OverflowOrUnderflowOccurred)?;
+        Ok((share(self.tokens_out)?, share(unspent)?))
+    }
+}
+
+//  one per bidder and auction, repeated bids add up
+#[account]
+#[derive(InitSpace)]
+pub struct Bid {
+    pub auction: Pubkey,
+    pub bidder: Pubkey,
+    pub amount: u64,
+    pub is_claimed: bool,
+}
+
+//  Resting limit order filled by keepers through fill_order
+//  a buy escrows amount + keeper_tip lamports in this account, a sell escrows
+//  amount tokens in this account's ata and keeper_tip lamports here
+#[account]
+#[derive(InitSpace)]
+pub struct Order {
+    pub owner: Pubkey,
+    pub bonding_curve: Pubkey,
+    pub token_mint: Pubkey,
+    pub direction: Direction,
+    pub amount: u64, //  lamports in when buying, tokens in when selling
+    pub limit_price: u64, //  in spot_price units, max price for a buy and min price for a sell
+    pub keeper_tip: u64, //  lamports paid to the keeper that fills the order
+    pub expires_at: i64,
+    pub nonce: u64,
+    pub bump: u8,
+}
+
+impl Order {
+    //  the least the order accepts for its amount, so it never fills worse than limit_price
+    pub fn minimum_receive_amount(&self) -> Result<u64> {
+        let value = match self.direction {
+            Direction::Buy => (self.amount as u128)
+                .checked_mul(PRICE_SCALE)
+                .and_then(|value| value.checked_div(self.limit_price as u128)),
+            Direction::Sell => (self.amount as u128)
+                .checked_mul(self.limit_price as u128)
+                .and_then(|value| value.checked_div(PRICE_SCALE)),
+        };
+        Ok(value
+            .ok_or(PumpfunError::OverflowOrUnderflowOccurred)?
+            .min(u64::MAX as u128) as u64)
+    }
+
+    //  the curve price has crossed the limit in the order's favour
+    pub fn is_triggered(&self, spot_price: u64) -> bool {
+        match self.direction {
+            Direction::Buy => spot_price <= self.limit_price,
+            Direction::Sell => spot_price >= self.limit_price,
+        }
+    }
+}
+
+//  amounts moved by a swap, all quote amounts are in the curve's quote units
+pub struct SwapAmounts {
+    pub amount_in: u64,
+    pub amount_out: u64,
+    pub fee: u64,
+}
+
+//  per-creator counter for launches with a PDA mint
+//  the next mint address is derived from the creator and launch_count
+#[account]
+#[derive(InitSpace)]
+pub struct LaunchCounter {
+    pub creator: Pubkey,
+    pub launch_count: u64,
 }
+
 pub trait BondingCurveAccount<'info> {
     // Updates the token reserves in the liquidity pool
     fn update_reserves(
         &mut self,
-        global_config: &Account<'info, Config>,
         reserve_one: u64,
         reserve_two: u64,
     ) -> Result<bool>;
+    // Prices a swap against the curve and updates the reserves, without moving any funds
+    #[allow(clippy::too_many_arguments)]
+    fn apply_swap(
+        &mut self,
+        bonding_curve: &Pubkey,
+        fee_config: &FeeConfig,
+        token_mint: &Account<'info, Mint>,
+        fee_vault: &mut FeeVault,
+        trader_record: &mut TraderRecord,
+        amount: u64,
+        direction: Direction,
+        minimum_receive_amount: u64,
+        deadline: i64,
+        user: &Pubkey,
+    ) -> Result<SwapAmounts>;
     #[allow(clippy::too_many_arguments)]
     fn swap(
         &mut self,
-        global_config: &Account<'info, Config>,
+        bonding_curve: &Pubkey,
+        fee_config: &FeeConfig,
         token_mint: &Account<'info, Mint>,
         global_ata: &mut AccountInfo<'info>,
         user_ata: &mut AccountInfo<'info>,
         source: &mut AccountInfo<'info>,
-        team_wallet: &mut AccountInfo<'info>,
-        team_wallet_ata: &mut AccountInfo<'info>,
+        fee_vault: &mut FeeVault,
+        trader_record: &mut TraderRecord,
         amount: u64,
-        direction: u8,
+        direction: Direction,
         minimum_receive_amount: u64,
         deadline: i64,
 
@@ -111,54 +496,44 @@ pub trait BondingCurveAccount<'info> {
     fn cal_amount_out(
         &self,
         amount: u64, // Input amount (tokens if selling, SOL if buying)
-        direction: u8,
+        direction: Direction,
         platform_sell_fee: u128,
         platform_buy_fee: u128,
     ) -> Result<(u64, u64)>; // Returns (output_amount, fee_amount_in_sol)
 }
 
-impl<'info> BondingCurveAccount<'info> for Account<'info, BondingCurve> {
+impl<'info> BondingCurveAccount<'info> for BondingCurve {
     fn update_reserves(
         &mut self,
-        _global_config: &Account<'info, Config>,
         reserve_token: u64,
         reserve_lamport: u64,
     ) -> Result<bool> {
         self.reserve_token = reserve_token;
         self.reserve_lamport = reserve_lamport;
+
+        self.record_price(Clock::get()?.unix_timestamp);
     
         if reserve_lamport >= self.curve_limit {
-            msg!("curve is completed");
-            self.is_completed = true;
+            self.is_completed = 1;
             return Ok(true);
         }
     
         Ok(false)
     }
 
-    fn swap(
+    fn apply_swap(
         &mut self,
-        global_config: &Account<'info, Config>,
-
+        bonding_curve: &Pubkey,
+        fee_config: &FeeConfig,
         token_mint: &Account<'info, Mint>,
-        global_ata: &mut AccountInfo<'info>,
-        user_ata: &mut AccountInfo<'info>,
-
-        source: &mut AccountInfo<'info>,
-        team_wallet: &mut AccountInfo<'info>,
-        team_wallet_ata: &mut AccountInfo<'info>,
-
+        fee_vault: &mut FeeVault,
+        trader_record: &mut TraderRecord,
         amount: u64,
-        direction: u8,
+        direction: Direction,
         minimum_receive_amount: u64,
         deadline: i64,
-
-        user: &Signer<'info>,
-        signer: &[&[&[u8]]],
-
-        token_program: &Program<'info, Token>,
-        system_program: &Program<'info, System>,
-    ) -> Result<u64> {
+        user: &Pubkey,
+    ) -> Result<SwapAmounts> {
         if amount == 0 {
             return err!(PumpfunError::InvalidAmount);
         }
@@ -170,20 +545,21 @@ impl<'info> BondingCurveAccount<'info> for Account<'info, BondingCurve> {
             PumpfunError::TransactionExpired
         );
 
-        msg!("curve_limit: {:?} ", global_config.curve_limit);
-        msg!("reserve_lamport: {:?} ", self.reserve_lamport);
+        require!(
+            !self.is_expired(current_timestamp),
+            PumpfunError::CurveExpired
+        );
 
-        // if side = buy, amount to swap = min(amount, remaining reserve)
-        // let amount = if direction == 1 {
-        //     amount
-        // } else {
-        //     amount.min(global_config.curve_limit - self.reserve_lamport)
-        // };
+        require!(
+            !self.has_pending_auction(),
+            PumpfunError::AuctionNotSettled
+        );
 
         // if side = buy, amount to swap = min(amount, remaining reserve)
         // Calculate swap and refund amounts
-        let (amount_to_swap, refund_amount, adjusted_minimum_receive) = if direction == 1 {
-            (amount, 0, minimum_receive_amount)
+        // The part of a buy above the curve limit is never taken from the user
+        let (amount_to_swap, adjusted_minimum_receive) = if direction == Direction::Sell {
+            (amount, minimum_receive_amount)
         } else {
             let remaining = self.curve_limit.saturating_sub(self.reserve_lamport);
             if amount > remaining {
@@ -193,31 +569,26 @@ impl<'info> BondingCurveAccount<'info> for Account<'info, BondingCurve> {
                     convert_to_float(minimum_receive_amount, token_mint.decimals) * adjustment_ratio,
                     token_mint.decimals
                 );
-                (remaining, amount - remaining, adjusted_minimum)
+                (remaining, adjusted_minimum)
             } else {
-                (amount, 0, minimum_receive_amount)
+                (amount, minimum_receive_amount)
             }
         };
 
-        msg!("Mint: {:?} ", token_mint.key());
-        msg!("Swap: {:?} {:?} {:?} (Amount to Swap)", user.key(), direction, amount_to_swap);
-
         // xy = k => Constant product formula logic moved to cal_amount_out
 
         let (amount_out, sol_fee) = self.cal_amount_out(
             amount_to_swap,
             direction,
-            global_config.platform_sell_fee,
-            global_config.platform_buy_fee,
+            fee_config.platform_sell_fee as u128,
+            fee_config.platform_buy_fee as u128,
         )?;
 
-        msg!("Amount Out: {:?}, SOL Fee: {:?}", amount_out, sol_fee);
-
         if amount_out < adjusted_minimum_receive {
             return Err(PumpfunError::ReturnAmountTooSmall.into());
         }
 
-        if direction == 1 { // Selling Tokens for SOL
+        if direction == Direction::Sell { // Selling Tokens for SOL
             // amount_to_swap = input tokens
             // amount_out = net SOL output
             // sol_fee = fee in SOL
@@ -236,38 +607,7 @@ impl<'info> BondingCurveAccount<'info> for Account<'info, BondingCurve> {
                 .checked_sub(gross_sol_output) // Subtract the total SOL leaving the pool
                 .ok_or(PumpfunError::OverflowOrUnderflowOccurred)?;
 
-            self.update_reserves(global_config, new_reserve_token, new_reserve_lamport)?;
-
-            msg! {"Reserves: {:?} {:?}", new_reserve_token, new_reserve_lamport};
-
-            // Transfer tokens from user to pool
-            token_transfer_user(
-                user_ata.clone(),
-                user,
-                global_ata.clone(),
-                token_program,
-                amount_to_swap, // Transfer the full input token amount
-            )?;
-
-            // Transfer NET SOL from pool to user
-            sol_transfer_with_signer(
-                source.clone(), // global_vault
-                user.to_account_info(),
-                system_program,
-                signer,
-                amount_out, // Transfer net SOL amount
-            )?;
-
-            // Transfer SOL fee from pool to team wallet
-            if sol_fee > 0 {
-                sol_transfer_with_signer(
-                    source.clone(), // global_vault
-                    team_wallet.clone(),
-                    system_program,
-                    signer,
-                    sol_fee,
-                )?;
-            }
+            self.update_reserves(new_reserve_token, new_reserve_lamport)?;
 
         } else { // Buying Tokens with SOL
             // amount_to_swap = input SOL used in calculation (potentially capped)
@@ -289,18 +629,113 @@ impl<'info> BondingCurveAccount<'info> for Account<'info, BondingCurve> {
                 .ok_or(PumpfunError::OverflowOrUnderflowOccurred)?;
 
             let is_completed =
-                self.update_reserves(global_config, new_reserve_token, new_reserve_lamport)?;
+                self.update_reserves(new_reserve_token, new_reserve_lamport)?;
 
             if is_completed {
                 emit!(CompleteEvent {
-                    user: user.key(),
+                    user: *user,
                     mint: token_mint.key(),
-                    bonding_curve: self.key()
+                    bonding_curve: *bonding_curve
                 });
             }
+        }
+
+        // The fee stays in the vault and is paid out by sweep_fees
+        fee_vault.accrue(sol_fee)?;
+
+        //  trading stats, saturating so they can never block a swap
+        if trader_record.first_trade_ts == 0 {
+            trader_record.bonding_curve = *bonding_curve;
+            trader_record.user = *user;
+            trader_record.first_trade_ts = current_timestamp;
+            self.unique_trader_count = self.unique_trader_count.saturating_add(1);
+        }
+        match direction {
+            Direction::Buy => {
+                self.total_buy_volume_lamports =
+                    self.total_buy_volume_lamports.saturating_add(amount_to_swap);
+            }
+            Direction::Sell => {
+                self.total_sell_volume_lamports = self
+                    .total_sell_volume_lamports
+                    .saturating_add(amount_out.saturating_add(sol_fee));
+            }
+        }
+        trader_record.record_trade(direction, amount_to_swap, amount_out);
+        self.total_fees_paid = self.total_fees_paid.saturating_add(sol_fee);
+        self.trade_count = self.trade_count.saturating_add(1);
+        self.last_trade_ts = current_timestamp;
+
+        Ok(SwapAmounts {
+            amount_in: amount_to_swap,
+            amount_out,
+            fee: sol_fee,
+        })
+    }
+
+    fn swap(
+        &mut self,
+        bonding_curve: &Pubkey,
+        fee_config: &FeeConfig,
+
+        token_mint: &Account<'info, Mint>,
+        global_ata: &mut AccountInfo<'info>,
+        user_ata: &mut AccountInfo<'info>,
+
+        source: &mut AccountInfo<'info>,
+        fee_vault: &mut FeeVault,
+        trader_record: &mut TraderRecord,
 
-            msg! {"Reserves: {:?} {:?}", new_reserve_token, new_reserve_lamport};
+        amount: u64,
+        direction: Direction,
+        minimum_receive_amount: u64,
+        deadline: i64,
+
+        user: &Signer<'info>,
+        signer: &[&[&[u8]]],
+
+        token_program: &Program<'info, Token>,
+        system_program: &Program<'info, System>,
+    ) -> Result<u64> {
+        let SwapAmounts {
+            amount_in: amount_to_swap,
+            amount_out,
+            fee: _,
+        } = self.apply_swap(
+            bonding_curve,
+            fee_config,
+            token_mint,
+            fee_vault,
+            trader_record,
+            amount,
+            direction,
+            minimum_receive_amount,
+            deadline,
+            user.key,
+        )?;
+
+        self.log_completion();
+
+        if direction == Direction::Sell { // Selling Tokens for SOL
+            // Transfer tokens from user to pool
+            token_transfer_user(
+                user_ata.clone(),
+                user,
+                global_ata.clone(),
+                token_program,
+                amount_to_swap, // Transfer the full input token amount
+            )?;
+
+            // Transfer NET SOL from pool to user
+            sol_transfer_with_signer(
+                source.clone(), // global_vault
+                user.to_account_info(),
+                system_program,
+                signer,
+                amount_out, // Transfer net SOL amount
+            )?;
 
+        } else { // Buying Tokens with SOL
             // Transfer tokens from pool to user
             token_transfer_with_signer(
                 global_ata.clone(),
@@ -314,27 +749,16 @@ impl<'info> BondingCurveAccount<'info> for Account<'info, BondingCurve> {
             // Transfer SOL from user to pool
             // User sends the full amount_to_swap (SOL potentially capped by curve limit)
             sol_transfer_from_user(user, source.clone(), system_program, amount_to_swap)?;
-
-            // Transfer SOL fee from pool to team wallet
-            // The fee was included in the amount_to_swap transferred from the user
-            if sol_fee > 0 {
-                sol_transfer_with_signer(
-                    source.clone(), // global_vault
-                    team_wallet.clone(),
-                    system_program,
-                    signer,
-                    sol_fee,
-                )?;
-            }
-
-            // Handle refund: The user only sent amount_to_swap, not the original 'amount'.
-            // The difference (refund_amount) was never sent, so no on-chain refund needed.
-            // The previous refund transfer was redundant.
-            // if refund_amount > 0 {
-            //     sol_transfer_from_user(user, user.to_account_info(), system_program, refund_amount)?;
-            // }
         }
-        msg!("SwapEvent: {:?} {:?} {:?}", user.key(), direction, amount_out);
+        log_swap(
+            &token_mint.key(),
+            user.key,
+            direction,
+            amount_to_swap,
+            amount_out,
+            self.reserve_token,
+            self.reserve_lamport,
+        );
         Ok(amount_out)
     }
 
@@ -342,13 +766,13 @@ impl<'info> BondingCurveAccount<'info> for Account<'info, BondingCurve> {
     fn cal_amount_out(
         &self,
         amount: u64, // Input amount (tokens if selling, SOL if buying)
-        direction: u8,
+        direction: Direction,
         platform_sell_fee: u128,
         platform_buy_fee: u128,
     ) -> Result<(u64, u64)> {
         let amount_u128 = amount as u128;
 
-        if direction == 1 {
+        if direction == Direction::Sell {
             // Selling tokens for SOL: dy = (y * dx) / (x + dx)
             // amount = dx (input tokens)
             // y = reserve_lamport, x = reserve_token
@@ -417,3 +841,264 @@ impl<'info> BondingCurveAccount<'info> for Account<'info, BondingCurve> {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn expired_curve() -> BondingCurve {
+        let mut curve: BondingCurve = bytemuck::Zeroable::zeroed();
+        curve.init_lamport = 30_000_000_000;
+        curve.init_reserve_token = 800_000_000;
+        //  200M tokens bought for 10 SOL
+        curve.reserve_token = 600_000_000;
+        curve.reserve_lamport = 40_000_000_000;
+        curve
+    }
+
+    #[test]
+    fn refund_amount_is_pro_rata_of_raised() {
+        let curve = expired_curve();
+        assert_eq!(curve.refund_amount(200_000_000).unwrap(), 10_000_000_000);
+        assert_eq!(curve.refund_amount(50_000_000).unwrap(), 2_500_000_000);
+    }
+
+    #[test]
+    fn refund_amount_keeps_later_shares_after_a_refund() {
+        let mut curve = expired_curve();
+        let first = curve.refund_amount(50_000_000).unwrap();
+        curve.reserve_lamport -= first;
+        curve.refunded_token += 50_000_000;
+
+        assert_eq!(curve.refund_amount(50_000_000).unwrap(), first);
+        assert_eq!(curve.refund_amount(150_000_000).unwrap(), 7_500_000_000);
+    }
+
+    #[test]
+    fn refund_amount_rejects_more_than_bought() {
+        let mut curve = expired_curve();
+        assert!(curve.refund_amount(0).is_err());
+        assert!(curve.refund_amount(200_000_001).is_err());
+
+        curve.refunded_token = 150_000_000;
+        assert!(curve.refund_amount(50_000_001).is_err());
+    }
+
+    #[test]
+    fn refund_amount_is_zero_without_raised_sol() {
+        let mut curve = expired_curve();
+        curve.reserve_lamport = curve.init_lamport;
+        assert_eq!(curve.refund_amount(200_000_000).unwrap(), 0);
+    }
+
+    fn trader_record(bought_token: u64) -> TraderRecord {
+        TraderRecord {
+            bonding_curve: Pubkey::default(),
+            user: Pubkey::default(),
+            first_trade_ts: 0,
+            bought_token,
+        }
+    }
+
+    #[test]
+    fn team_allocation_cannot_be_refunded() {
+        //  the team wallet received its allocation at launch and never bought
+        let mut team = trader_record(0);
+        assert!(team.take_refund(1).is_err());
+        assert_eq!(team.bought_token, 0);
+
+        //  so a buyer's share of the raised SOL stays available
+        let curve = expired_curve();
+        let mut buyer = trader_record(200_000_000);
+        buyer.take_refund(200_000_000).unwrap();
+        assert_eq!(curve.refund_amount(200_000_000).unwrap(), 10_000_000_000);
+    }
+
+    #[test]
+    fn refunds_are_capped_by_net_tokens_bought() {
+        let mut record = trader_record(0);
+        record.record_trade(Direction::Buy, 1_000_000_000, 300);
+        record.record_trade(Direction::Sell, 100, 300_000_000);
+        assert_eq!(record.bought_token, 200);
+
+        assert!(record.take_refund(201).is_err());
+        record.take_refund(150).unwrap();
+        record.take_refund(50).unwrap();
+        assert!(record.take_refund(1).is_err());
+    }
+
+    #[test]
+    fn selling_more_than_bought_saturates() {
+        //  tokens received by transfer can be sold, they just never count as bought
+        let mut record = trader_record(100);
+        record.record_trade(Direction::Sell, 500, 1);
+        assert_eq!(record.bought_token, 0);
+    }
+
+    fn settled_auction(total_bids: u64, lamports_used: u64, tokens_out: u64) -> Auction {
+        Auction {
+            bonding_curve: Pubkey::default(),
+            token_mint: Pubkey::default(),
+            ends_at: 0,
+            total_bids,
+            lamports_used,
+            tokens_out,
+            is_settled: true,
+            bump: 0,
+        }
+    }
+
+    #[test]
+    fn allocation_is_pro_rata_of_tokens_and_unspent_bids() {
+        //  4 SOL of bids, 3 SOL spent for 900 tokens
+        let auction = settled_auction(4_000_000_000, 3_000_000_000, 900);
+        assert_eq!(auction.allocation(1_000_000_000).unwrap(), (225, 250_000_000));
+        assert_eq!(auction.allocation(3_000_000_000).unwrap(), (675, 750_000_000));
+    }
+
+    #[test]
+    fn allocation_of_all_bids_is_everything() {
+        let auction = settled_auction(4_000_000_000, 3_000_000_000, 900);
+        assert_eq!(auction.allocation(4_000_000_000).unwrap(), (900, 1_000_000_000));
+    }
+
+    #[test]
+    fn allocation_rounds_down() {
+        //  three equal bids never pay out more than the auction holds
+        let auction = settled_auction(3, 1, 100);
+        let (tokens, refund) = auction.allocation(1).unwrap();
+        assert_eq!((tokens, refund), (33, 0));
+        assert!(tokens * 3 <= 100 && refund * 3 <= 2);
+    }
+
+    #[test]
+    fn allocation_refunds_everything_when_nothing_was_spent() {
+        //  settled on a curve the launch buy already completed
+        let auction = settled_auction(2_000_000_000, 0, 0);
+        assert_eq!(auction.allocation(500_000_000).unwrap(), (0, 500_000_000));
+    }
+
+    #[test]
+    fn allocation_of_large_bids_does_not_overflow() {
+        let auction = settled_auction(u64::MAX, u64::MAX / 2, u64::MAX);
+        assert_eq!(auction.allocation(u64::MAX).unwrap(), (u64::MAX, u64::MAX - u64::MAX / 2));
+    }
+
+    fn order(direction: Direction, amount: u64, limit_price: u64) -> Order {
+        Order {
+            owner: Pubkey::default(),
+            bonding_curve: Pubkey::default(),
+            token_mint: Pubkey::default(),
+            direction,
+            amount,
+            limit_price,
+            keeper_tip: 0,
+            expires_at: 0,
+            nonce: 0,
+            bump: 0,
+        }
+    }
+
+    #[test]
+    fn buy_order_receives_at_least_amount_over_limit_price() {
+        //  at most 0.5 lamports per token, scaled
+        let buy = order(Direction::Buy, 1_000, PRICE_SCALE as u64 / 2);
+        assert_eq!(buy.minimum_receive_amount().unwrap(), 2_000);
+
+        //  rounds down so a fill exactly at the limit passes
+        let buy = order(Direction::Buy, 1_000, 3 * PRICE_SCALE as u64);
+        assert_eq!(buy.minimum_receive_amount().unwrap(), 333);
+    }
+
+    #[test]
+    fn sell_order_receives_at_least_amount_times_limit_price() {
+        let sell = order(Direction::Sell, 2_000, PRICE_SCALE as u64 / 2);
+        assert_eq!(sell.minimum_receive_amount().unwrap(), 1_000);
+
+        let sell = order(Direction::Sell, 1_000, 1);
+        assert_eq!(sell.minimum_receive_amount().unwrap(), 0);
+    }
+
+    #[test]
+    fn minimum_receive_amount_saturates_at_u64_max() {
+        let buy = order(Direction::Buy, u64::MAX, 1);
+        assert_eq!(buy.minimum_receive_amount().unwrap(), u64::MAX);
+
+        let sell = order(Direction::Sell, u64::MAX, u64::MAX);
+        assert_eq!(sell.minimum_receive_amount().unwrap(), u64::MAX);
+    }
+
+    #[test]
+    fn buy_order_with_zero_limit_price_errors() {
+        let buy = order(Direction::Buy, 1_000, 0);
+        assert!(buy.minimum_receive_amount().is_err());
+    }
+
+    fn priced_curve(price: u64) -> BondingCurve {
+        let mut curve: BondingCurve = bytemuck::Zeroable::zeroed();
+        curve.reserve_token = PRICE_SCALE as u64;
+        curve.reserve_lamport = price;
+        curve
+    }
+
+    #[test]
+    fn trades_within_an_interval_share_one_observation() {
+        let mut curve = priced_curve(100);
+        curve.record_price(1_000);
+        for second in 1..TWAP_OBSERVATION_INTERVAL {
+            curve.reserve_lamport = 100 + second as u64;
+            curve.record_price(1_000 + second);
+        }
+        let recorded = curve.observations.iter().filter(|o| o.timestamp != 0).count();
+        assert_eq!(recorded, 1);
+
+        curve.record_price(1_000 + TWAP_OBSERVATION_INTERVAL);
+        let recorded = curve.observations.iter().filter(|o| o.timestamp != 0).count();
+        assert_eq!(recorded, 2);
+    }
+
+    #[test]
+    fn burst_of_trades_does_not_flush_the_ring() {
+        let mut curve = priced_curve(100);
+        curve.record_price(1_000);
+        curve.record_price(1_000 + TWAP_OBSERVATION_INTERVAL);
+
+        //  a spike traded many times within a few seconds
+        curve.reserve_lamport = 10_000;
+        for second in 0..(2 * TWAP_OBSERVATIONS as i64) {
+            curve.record_price(1_000 + TWAP_OBSERVATION_INTERVAL + 1 + second / 4);
+        }
+        curve.reserve_lamport = 100;
+        curve.record_price(1_000 + TWAP_OBSERVATION_INTERVAL + 5);
+
+        //  the window before the spike still reads the old price
+        let now = 1_000 + TWAP_OBSERVATION_INTERVAL;
+        assert_eq!(curve.twap(now, TWAP_OBSERVATION_INTERVAL as u32).unwrap(), 100);
+        //  and the spike only weighs for the seconds it held
+        let now = 1_000 + 2 * TWAP_OBSERVATION_INTERVAL;
+        let twap = curve.twap(now, 2 * TWAP_OBSERVATION_INTERVAL as u32).unwrap();
+        assert_eq!(twap, (100 * 116 + 10_000 * 4) / 120);
+    }
+
+    #[test]
+    fn twap_rejects_windows_older_than_the_ring() {
+        let mut curve = priced_curve(100);
+        curve.record_price(1_000);
+        assert_eq!(curve.twap(1_010, 10).unwrap(), 100);
+        assert!(curve.twap(1_010, 11).is_err());
+        assert!(curve.twap(1_010, 0).is_err());
+    }
+
+    #[test]
+    fn orders_trigger_when_price_crosses_the_limit() {
+        let buy = order(Direction::Buy, 1_000, 100);
+        assert!(buy.is_triggered(99));
+        assert!(buy.is_triggered(100));
+        assert!(!buy.is_triggered(101));
+
+        let sell = order(Direction::Sell, 1_000, 100);
+        assert!(!sell.is_triggered(99));
+        assert!(sell.is_triggered(100));
+        assert!(sell.is_triggered(101));
+    }
+}
@@ -1,4 +1,5 @@
 use crate::*;
+use anchor_spl::associated_token::{self, AssociatedToken};
 use anchor_spl::token::{self, Token};
 use anchor_lang::solana_program;
 
@@ -61,6 +62,17 @@ pub fn sol_transfer_with_signer<'info>(
     Ok(())
 }
 
+//  transfer sol out of an account owned by this program, like an auction escrow
+pub fn sol_transfer_from_program_account<'info>(
+    source: AccountInfo<'info>,
+    destination: AccountInfo<'info>,
+    amount: u64,
+) -> Result<()> {
+    source.sub_lamports(amount)?;
+    destination.add_lamports(amount)?;
+    Ok(())
+}
+
 //  transfer token from user
 pub fn token_transfer_user<'info>(
     from: AccountInfo<'info>,
@@ -104,3 +116,57 @@ pub fn token_transfer_with_signer<'info>(
 
     Ok(())
 }
+
+//  closes the global vault's wrapped SOL ata into the vault as native SOL and opens it again,
+//  so wrapped SOL paid in is available to native sells and withdraw
+pub fn unwrap_global_wsol<'info>(
+    global_wsol_account: AccountInfo<'info>,
+    global_vault: AccountInfo<'info>,
+    native_mint: AccountInfo<'info>,
+    token_program: &Program<'info, Token>,
+    associated_token_program: &Program<'info, AssociatedToken>,
+    system_program: &Program<'info, System>,
+    signer_seeds: &[&[&[u8]]],
+) -> Result<()> {
+    token::close_account(CpiContext::new_with_signer(
+        token_program.to_account_info(),
+        token::CloseAccount {
+            account: global_wsol_account.clone(),
+            destination: global_vault.clone(),
+            authority: global_vault.clone(),
+        },
+        signer_seeds,
+    ))?;
+
+    //  the vault pays the rent it just got back
+    associated_token::create(CpiContext::new_with_signer(
+        associated_token_program.to_account_info(),
+        associated_token::Create {
+            payer: global_vault.clone(),
+            associated_token: global_wsol_account,
+            authority: global_vault,
+            mint: native_mint,
+            system_program: system_program.to_account_info(),
+            token_program: token_program.to_account_info(),
+        },
+        signer_seeds,
+    ))
+}
+
+//  swap logs parsed by the backend indexer, direction is 0: buy, 1: sell
+//  swap_v2 skips these to save compute, its result is in the return data
+pub fn log_swap(
+    mint: &Pubkey,
+    user: &Pubkey,
+    direction: state::Direction,
+    amount_in: u64,
+    amount_out: u64,
+    reserve_token: u64,
+    reserve_lamport: u64,
+) {
+    let direction = u8::from(direction);
+    msg!("Mint: {}", mint);
+    msg!("Swap: {} {} {}", user, direction, amount_in);
+    msg!("Reserves: {} {}", reserve_token, reserve_lamport);
+    msg!("SwapEvent: {} {} {}", user, direction, amount_out);
+}
@@ -2,6 +2,19 @@ pub const VAULT_CONFIG_SEED: &[u8] = b"raydium_vault_config";
 pub const POSITION_SEED: &[u8] = b"raydium_position";
 pub const CLAIMER_INFO_SEED: &[u8] = b"raydium_claimer_info";
 pub const NFT_FAUCET_SEED: &[u8] = b"raydium_vault_nft_seed";
+pub const SOL_CLAIM_SEED: &[u8] = b"raydium_vault_sol_claim";
+pub const EMERGENCY_WITHDRAW_SEED: &[u8] = b"raydium_vault_emergency_withdraw";
+
+// Maximum number of fee shares per position
+pub const MAX_FEE_SHARES: usize = 4;
+pub const BPS_DENOMINATOR: u64 = 10_000;
+// Upper bound of the protocol's cut of claimed fees
+pub const MAX_PROTOCOL_FEE_BPS: u16 = 5_000;
+// Bounds of the emergency withdraw delay, 1 to 30 days
+pub const MIN_EMERGENCY_WITHDRAW_DELAY: i64 = 24 * 60 * 60;
+pub const MAX_EMERGENCY_WITHDRAW_DELAY: i64 = 30 * 24 * 60 * 60;
+// Emergency withdraw delay of new and migrated vault configs, 3 days
+pub const DEFAULT_EMERGENCY_WITHDRAW_DELAY: i64 = 3 * 24 * 60 * 60;
 
 // Maximum signature size
 pub const SIGNATURE_SIZE: usize = 64;
@@ -37,4 +37,52 @@ pub enum VaultError {
 
     #[msg("Insufficient Balance")]
     InsufficientBalance,
+
+    #[msg("No pending nomination")]
+    NoPendingNomination,
+
+    #[msg("Invalid vault config account")]
+    InvalidVaultConfig,
+
+    #[msg("Fee shares must be non-zero and add up to 100%")]
+    InvalidFeeShares,
+
+    #[msg("Too many fee shares")]
+    TooManyFeeShares,
+
+    #[msg("Invalid fee share recipient account")]
+    InvalidFeeRecipient,
+
+    #[msg("Protocol fee too high")]
+    ProtocolFeeTooHigh,
+
+    #[msg("Protocol fee accounts required")]
+    MissingProtocolFeeAccount,
+
+    #[msg("Pool has no SOL side")]
+    NotSolPool,
+
+    #[msg("Not supported for positions with fee shares")]
+    FeeSharesNotSupported,
+
+    #[msg("Compounding is not enabled for this position")]
+    CompoundNotEnabled,
+
+    #[msg("No fees to compound")]
+    NoFeesToCompound,
+
+    #[msg("Invalid emergency withdraw delay")]
+    InvalidEmergencyWithdrawDelay,
+
+    #[msg("Emergency withdraw destination must belong to the claimer or the recovery address")]
+    InvalidWithdrawDestination,
+
+    #[msg("Emergency withdraw delay has not passed")]
+    EmergencyWithdrawPending,
+
+    #[msg("Emergency withdraw veto window has passed")]
+    VetoWindowClosed,
+
+    #[msg("Emergency withdraw was vetoed too recently")]
+    VetoCooldown,
 }
@@ -1,5 +1,7 @@
 use anchor_lang::prelude::*;
 
+use crate::state::{ AuthorityRole, FeeShare };
+
 #[event]
 pub struct VaultInitialized {
     pub executor: Pubkey,
@@ -31,11 +33,133 @@ pub struct ManagerChanged {
     pub new_manager: Pubkey,
 }
 
+#[event]
+pub struct AuthorityNominated {
+    pub role: AuthorityRole,
+    pub current: Pubkey,
+    pub nominee: Pubkey,
+}
+
+#[event]
+pub struct AuthorityNominationCancelled {
+    pub role: AuthorityRole,
+    pub nominee: Pubkey,
+}
+
+#[event]
+pub struct ProtocolFeeChanged {
+    pub protocol_fee_bps: u16,
+    pub protocol_fee_recipient: Pubkey,
+}
+
+#[event]
+pub struct ProtocolFeeWithheld {
+    pub position_nft: Pubkey,
+    pub recipient: Pubkey,
+    pub amount_0: u64,
+    pub amount_1: u64,
+}
+
 #[event]
 pub struct CpFeeCollected {
     pub claimer: Pubkey,
     pub position_nft: Pubkey,
     pub claimed_time: i64,
+    pub mint_0: Pubkey,
+    pub amount_0: u64,
+    pub mint_1: Pubkey,
+    pub amount_1: u64,
+}
+
+#[event]
+pub struct PermissionlessClaimChanged {
+    pub claimer: Pubkey,
+    pub position_nft: Pubkey,
+    pub enabled: bool,
+}
+
+#[event]
+pub struct FeeSharesChanged {
+    pub position_nft: Pubkey,
+    pub fee_shares: Vec<FeeShare>,
+}
+
+#[event]
+pub struct FeeShareDistributed {
+    pub position_nft: Pubkey,
+    pub recipient: Pubkey,
+    pub amount_0: u64,
+    pub amount_1: u64,
+}
+
+#[event]
+pub struct CompoundChanged {
+    pub claimer: Pubkey,
+    pub position_nft: Pubkey,
+    pub enabled: bool,
+}
+
+/// Fees deposited back into the pool, locked as new_position_nft
+#[event]
+pub struct FeesCompounded {
+    pub claimer: Pubkey,
+    pub position_nft: Pubkey,
+    pub new_position_nft: Pubkey,
+    /// Amounts deposited, what was left over went to the claimer
+    pub amount_0: u64,
+    pub amount_1: u64,
+    pub lp_amount: u64,
+}
+
+/// Fees claimed as native SOL, follows the position's CpFeeCollected
+#[event]
+pub struct FeesClaimedAsSol {
+    pub claimer: Pubkey,
+    pub position_nft: Pubkey,
+    /// Token side amount swapped into WSOL
+    pub token_swapped: u64,
+    /// Lamports paid to the claimer
+    pub sol_amount: u64,
+    /// Part of sol_amount that came out of the swap
+    pub swap_amount_out: u64,
+}
+
+/// Summary of a claim_many batch
+#[event]
+pub struct BatchClaimed {
+    pub claimed_time: i64,
+    /// Positions that had fees collected
+    pub claimed: u16,
+    /// Positions skipped because there were no fees to collect
+    pub skipped: u16,
+}
+
+#[event]
+pub struct EmergencyWithdrawConfigChanged {
+    pub emergency_withdraw_delay: i64,
+    pub recovery_address: Pubkey,
+}
+
+#[event]
+pub struct EmergencyWithdrawRequested {
+    pub claimer: Pubkey,
+    pub position_nft: Pubkey,
+    pub destination: Pubkey,
+    /// Reason code recorded by the emergency authority
+    pub reason: u16,
+    pub executable_at: i64,
+}
+
+#[event]
+pub struct EmergencyWithdrawVetoed {
+    pub claimer: Pubkey,
+    pub position_nft: Pubkey,
+}
+
+#[event]
+pub struct EmergencyWithdrawCancelled {
+    pub claimer: Pubkey,
+    pub position_nft: Pubkey,
 }
 
 #[event]
@@ -50,3 +174,20 @@ pub struct ClaimerChanged {
     pub old_claimer: Pubkey,
     pub new_claimer: Pubkey,
 }
+
+#[event]
+pub struct ClaimerNominated {
+    pub position_nft: Pubkey,
+    pub claimer: Pubkey,
+    pub nominee: Pubkey,
+}
+
+/// Executor override of a position's claimer
+#[event]
+pub struct ClaimerOverridden {
+    pub position_nft: Pubkey,
+    pub old_claimer: Pubkey,
+    pub new_claimer: Pubkey,
+    /// Support reason code recorded by the executor
+    pub reason: u16,
+}
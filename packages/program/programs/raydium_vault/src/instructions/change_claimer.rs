@@ -2,13 +2,14 @@ use anchor_lang::prelude::*;
 use anchor_spl::token::Mint;
 
 use crate::{
-    constants::{ POSITION_SEED, VAULT_CONFIG_SEED },
+    constants::{ MAX_FEE_SHARES, POSITION_SEED, VAULT_CONFIG_SEED },
     errors::VaultError,
-    events::ClaimerChanged,
-    state::{ UserPosition, VaultConfig },
+    events::{ ClaimerChanged, ClaimerNominated, ClaimerOverridden, FeeSharesChanged },
+    state::{ FeeShare, UserPosition, VaultConfig },
+    utils::get_current_timestamp,
 };
 
-// Change claimer address
+// Change claimer address, executor override
 #[derive(Accounts)]
 pub struct ChangeClaimer<'info> {
     #[account(mut, constraint = authority.key() == vault_config.executor_authority.key() @VaultError::Unauthorized)]
@@ -17,16 +18,94 @@ pub struct ChangeClaimer<'info> {
     #[account(seeds = [VAULT_CONFIG_SEED], bump)]
     pub vault_config: Account<'info, VaultConfig>,
 
-    #[account(seeds = [POSITION_SEED, position_nft.key().as_ref()], bump)]
+    #[account(mut, seeds = [POSITION_SEED, position_nft.key().as_ref()], bump)]
     pub user_position: Account<'info, UserPosition>,
 
     // Position token mint
     pub position_nft: Account<'info, Mint>,
 }
 
-pub fn process_change_claimer(ctx: Context<ChangeClaimer>, new_claimer: Pubkey) -> Result<()> {
-    let old_claimer = ctx.accounts.user_position.claimer;
-    ctx.accounts.user_position.claimer = new_claimer;
+// Current claimer nominates a new claimer
+#[derive(Accounts)]
+pub struct NominateClaimer<'info> {
+    #[account(constraint = claimer.key() == user_position.claimer.key() @VaultError::Unauthorized)]
+    pub claimer: Signer<'info>,
+
+    #[account(mut, seeds = [POSITION_SEED, position_nft.key().as_ref()], bump)]
+    pub user_position: Account<'info, UserPosition>,
+
+    // Position token mint
+    pub position_nft: Account<'info, Mint>,
+}
+
+// Nominated claimer takes over the position
+#[derive(Accounts)]
+pub struct AcceptClaimer<'info> {
+    #[account(constraint = new_claimer.key() == user_position.pending_claimer.key() @VaultError::Unauthorized)]
+    pub new_claimer: Signer<'info>,
+
+    #[account(mut, seeds = [POSITION_SEED, position_nft.key().as_ref()], bump)]
+    pub user_position: Account<'info, UserPosition>,
+
+    // Position token mint
+    pub position_nft: Account<'info, Mint>,
+}
+
+pub fn process_change_claimer(
+    ctx: Context<ChangeClaimer>,
+    new_claimer: Pubkey,
+    reason: u16
+) -> Result<()> {
+    let position = &mut ctx.accounts.user_position;
+    let old_claimer = position.claimer;
+    hand_over(position, new_claimer)?;
+
+    // Fee shares were set up for the old claimer, so fees go to the new claimer until they are set again.
+    // A claimer's own handover keeps them, since only the executor may change fee shares
+    if position.fee_share_count > 0 {
+        position.fee_shares = [FeeShare::default(); MAX_FEE_SHARES];
+        position.fee_share_count = 0;
+
+        emit!(FeeSharesChanged {
+            position_nft: position.position_nft,
+            fee_shares: Vec::new(),
+        });
+    }
+
+    emit!(ClaimerOverridden {
+        position_nft: position.position_nft,
+        old_claimer,
+        new_claimer,
+        reason,
+    });
+
+    Ok(())
+}
+
+// Nominating Pubkey::default() cancels a pending nomination
+pub fn process_nominate_claimer(ctx: Context<NominateClaimer>, new_claimer: Pubkey) -> Result<()> {
+    let position = &mut ctx.accounts.user_position;
+    position.pending_claimer = new_claimer;
+    position.last_updated = get_current_timestamp()?;
+
+    emit!(ClaimerNominated {
+        position_nft: position.position_nft,
+        claimer: position.claimer,
+        nominee: new_claimer,
+    });
+
+    Ok(())
+}
+
+pub fn process_accept_claimer(ctx: Context<AcceptClaimer>) -> Result<()> {
+    hand_over(&mut ctx.accounts.user_position, ctx.accounts.new_claimer.key())
+}
+
+fn hand_over(position: &mut UserPosition, new_claimer: Pubkey) -> Result<()> {
+    let old_claimer = position.claimer;
+    position.claimer = new_claimer;
+    position.pending_claimer = Pubkey::default();
+    position.last_updated = get_current_timestamp()?;
 
     emit!(ClaimerChanged { old_claimer, new_claimer });
 
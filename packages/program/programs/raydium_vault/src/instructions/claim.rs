@@ -1,31 +1,39 @@
 use anchor_lang::prelude::*;
 use anchor_spl::{
+    associated_token::AssociatedToken,
     memo::Memo,
     token::Token,
     token_2022::Token2022,
-    token_interface::{ Mint, TokenAccount },
+    token_interface::{ Mint, TokenAccount, TokenInterface },
 };
 use raydium_cpmm_cpi::program::RaydiumCpmm;
 use raydium_locking_cpi::{ cpi, program::RaydiumLiquidityLocking, states::LockedCpLiquidityState };
 
 use crate::{
-    constants::{ POSITION_SEED, VAULT_CONFIG_SEED },
+    constants::{ BPS_DENOMINATOR, POSITION_SEED, VAULT_CONFIG_SEED },
     errors::VaultError,
-    events::CpFeeCollected,
+    events::{ CpFeeCollected, FeeShareDistributed, ProtocolFeeWithheld },
     state::{ UserPosition, VaultConfig },
-    utils::get_current_timestamp,
+    utils::{ get_current_timestamp, transfer_from_vault },
 };
 
 // Claim instructions
+// Signed by the executor or the position's claimer, or by anyone if the claimer enabled permissionless claims.
+// Fees always go to the claimer's token accounts.
 #[derive(Accounts)]
 pub struct Claim<'info> {
-    #[account(mut, constraint = authority.key() == vault_config.executor_authority.key() @VaultError::Unauthorized)]
+    #[account(
+        mut,
+        constraint = authority.key() == vault_config.executor_authority.key()
+            || authority.key() == user_position.claimer.key()
+            || user_position.permissionless_claim @VaultError::Unauthorized
+    )]
     pub authority: Signer<'info>,
 
     #[account(seeds = [VAULT_CONFIG_SEED], bump)]
     pub vault_config: Account<'info, VaultConfig>,
 
-    #[account(seeds = [POSITION_SEED, locked_liquidity.fee_nft_mint.key().as_ref()], bump)]
+    #[account(mut, seeds = [POSITION_SEED, locked_liquidity.fee_nft_mint.key().as_ref()], bump)]
     pub user_position: Account<'info, UserPosition>,
 
     pub locking_program: Program<'info, RaydiumLiquidityLocking>,
@@ -80,7 +88,7 @@ pub struct Claim<'info> {
     #[account(mut)]
     pub lp_mint: Box<InterfaceAccount<'info, Mint>>,
 
-    /// The token account for receive token_0
+    /// The token account for receive token_0, used when the position has no fee shares
     #[account(
         mut,
         associated_token::mint = token_0_vault.mint,
@@ -88,7 +96,7 @@ pub struct Claim<'info> {
     )]
     pub recipient_token_0_account: Box<InterfaceAccount<'info, TokenAccount>>,
 
-    /// The token account for receive token_1
+    /// The token account for receive token_1, used when the position has no fee shares
     #[account(
         mut,
         associated_token::mint = token_1_vault.mint,
@@ -96,6 +104,44 @@ pub struct Claim<'info> {
     )]
     pub recipient_token_1_account: Box<InterfaceAccount<'info, TokenAccount>>,
 
+    /// Vault-owned token account token_0 fees are collected into, then distributed
+    /// Created by the first claim on the mint
+    #[account(
+        init_if_needed,
+        payer = authority,
+        associated_token::mint = vault_0_mint,
+        associated_token::authority = vault_config,
+        associated_token::token_program = token_0_program
+    )]
+    pub vault_token_0_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// Vault-owned token account token_1 fees are collected into, then distributed
+    /// Created by the first claim on the mint
+    #[account(
+        init_if_needed,
+        payer = authority,
+        associated_token::mint = vault_1_mint,
+        associated_token::authority = vault_config,
+        associated_token::token_program = token_1_program
+    )]
+    pub vault_token_1_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// Protocol fee token account for token_0, required when the protocol fee is set
+    #[account(
+        mut,
+        token::mint = token_0_vault.mint,
+        token::authority = vault_config.protocol_fee_recipient
+    )]
+    pub protocol_token_0_account: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+
+    /// Protocol fee token account for token_1, required when the protocol fee is set
+    #[account(
+        mut,
+        token::mint = token_1_vault.mint,
+        token::authority = vault_config.protocol_fee_recipient
+    )]
+    pub protocol_token_1_account: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+
     /// The address that holds pool tokens for token_0
     /// address = pool_state.token_0_vault
     #[account(mut)]
@@ -129,9 +175,21 @@ pub struct Claim<'info> {
     /// Token program 2022
     pub token_program_2022: Program<'info, Token2022>,
 
+    /// Token program of the token_0 mint
+    #[account(address = *vault_0_mint.to_account_info().owner)]
+    pub token_0_program: Interface<'info, TokenInterface>,
+
+    /// Token program of the token_1 mint
+    #[account(address = *vault_1_mint.to_account_info().owner)]
+    pub token_1_program: Interface<'info, TokenInterface>,
+
     /// memo program
     #[account()]
     pub memo_program: Program<'info, Memo>,
+
+    pub associated_token_program: Program<'info, AssociatedToken>,
+
+    pub system_program: Program<'info, System>,
 }
 
 #[inline(never)]
@@ -156,8 +214,8 @@ pub fn process_claim<'a, 'b, 'c: 'info, 'info>(
         cp_authority: ctx.accounts.cp_authority.to_account_info(),
         pool_state: ctx.accounts.pool_state.to_account_info(),
         lp_mint: ctx.accounts.lp_mint.to_account_info(),
-        recipient_token_0_account: ctx.accounts.recipient_token_0_account.to_account_info(),
-        recipient_token_1_account: ctx.accounts.recipient_token_1_account.to_account_info(),
+        recipient_token_0_account: ctx.accounts.vault_token_0_account.to_account_info(),
+        recipient_token_1_account: ctx.accounts.vault_token_1_account.to_account_info(),
         token_0_vault: ctx.accounts.token_0_vault.to_account_info(),
         token_1_vault: ctx.accounts.token_1_vault.to_account_info(),
         vault_0_mint: ctx.accounts.vault_0_mint.to_account_info(),
@@ -167,20 +225,278 @@ pub fn process_claim<'a, 'b, 'c: 'info, 'info>(
         token_program_2022: ctx.accounts.token_program_2022.to_account_info(),
         memo_program: ctx.accounts.memo_program.to_account_info(),
     };
-    let cpi_context = CpiContext::new_with_signer(
+    let (amount_0, amount_1) = collect_into_vault(
         ctx.accounts.locking_program.to_account_info(),
         cpi_accounts,
+        &mut ctx.accounts.vault_token_0_account,
+        &mut ctx.accounts.vault_token_1_account,
         signer_seeds
+    )?;
+
+    let accounts = &ctx.accounts;
+    pay_out_fees(
+        &FeeAccounts {
+            vault_config: &accounts.vault_config,
+            user_position: &accounts.user_position,
+            vault_token_0_account: accounts.vault_token_0_account.to_account_info(),
+            vault_token_1_account: accounts.vault_token_1_account.to_account_info(),
+            vault_0_mint: &accounts.vault_0_mint,
+            vault_1_mint: &accounts.vault_1_mint,
+            protocol_token_0_account: accounts.protocol_token_0_account
+                .as_ref()
+                .map(|account| account.to_account_info()),
+            protocol_token_1_account: accounts.protocol_token_1_account
+                .as_ref()
+                .map(|account| account.to_account_info()),
+            token_program: accounts.token_program.to_account_info(),
+            token_program_2022: accounts.token_program_2022.to_account_info(),
+        },
+        accounts.recipient_token_0_account.to_account_info(),
+        accounts.recipient_token_1_account.to_account_info(),
+        ctx.remaining_accounts,
+        signer_seeds,
+        amount_0,
+        amount_1
+    )?;
+
+    record_claim(
+        &mut ctx.accounts.user_position,
+        ctx.accounts.vault_0_mint.key(),
+        amount_0,
+        ctx.accounts.vault_1_mint.key(),
+        amount_1,
+        current_time
     );
-    cpi::collect_cp_fees(cpi_context, u64::MAX)?;
 
-    ctx.accounts.user_position.last_updated = current_time;
+    Ok(())
+}
+
+// Collects a locked position's fees into the vault token accounts.
+// Balances are measured around the CPI, so the amounts are this position's fees only.
+pub(crate) fn collect_into_vault<'info>(
+    locking_program: AccountInfo<'info>,
+    cpi_accounts: cpi::accounts::CollectCpFee<'info>,
+    vault_token_0_account: &mut InterfaceAccount<'info, TokenAccount>,
+    vault_token_1_account: &mut InterfaceAccount<'info, TokenAccount>,
+    signer_seeds: &[&[&[u8]]]
+) -> Result<(u64, u64)> {
+    let balance_0_before = vault_token_0_account.amount;
+    let balance_1_before = vault_token_1_account.amount;
+
+    cpi::collect_cp_fees(
+        CpiContext::new_with_signer(locking_program, cpi_accounts, signer_seeds),
+        u64::MAX
+    )?;
+
+    vault_token_0_account.reload()?;
+    vault_token_1_account.reload()?;
+    Ok((
+        vault_token_0_account.amount.saturating_sub(balance_0_before),
+        vault_token_1_account.amount.saturating_sub(balance_1_before),
+    ))
+}
+
+// Adds a claim to the position's lifetime totals and emits it
+pub(crate) fn record_claim(
+    position: &mut UserPosition,
+    mint_0: Pubkey,
+    amount_0: u64,
+    mint_1: Pubkey,
+    amount_1: u64,
+    current_time: i64
+) {
+    position.last_updated = current_time;
+    position.total_claimed_0 = position.total_claimed_0.saturating_add(amount_0);
+    position.total_claimed_1 = position.total_claimed_1.saturating_add(amount_1);
 
     emit!(CpFeeCollected {
-        claimer: ctx.accounts.user_position.claimer.key(),
-        position_nft: ctx.accounts.locked_liquidity.fee_nft_mint.key(),
+        claimer: position.claimer,
+        position_nft: position.position_nft,
         claimed_time: current_time,
+        mint_0,
+        amount_0,
+        mint_1,
+        amount_1,
     });
+}
+
+// Accounts collected fees are paid out of and to, shared by claim and claim_many
+pub(crate) struct FeeAccounts<'a, 'info> {
+    pub vault_config: &'a Account<'info, VaultConfig>,
+    pub user_position: &'a UserPosition,
+    pub vault_token_0_account: AccountInfo<'info>,
+    pub vault_token_1_account: AccountInfo<'info>,
+    pub vault_0_mint: &'a InterfaceAccount<'info, Mint>,
+    pub vault_1_mint: &'a InterfaceAccount<'info, Mint>,
+    pub protocol_token_0_account: Option<AccountInfo<'info>>,
+    pub protocol_token_1_account: Option<AccountInfo<'info>>,
+    pub token_program: AccountInfo<'info>,
+    pub token_program_2022: AccountInfo<'info>,
+}
+
+impl<'info> FeeAccounts<'_, 'info> {
+    pub(crate) fn token_program_for(&self, mint: &InterfaceAccount<'info, Mint>) -> AccountInfo<'info> {
+        if *mint.to_account_info().owner == self.token_program_2022.key() {
+            self.token_program_2022.clone()
+        } else {
+            self.token_program.clone()
+        }
+    }
 
+    pub(crate) fn transfer_0(&self, to: AccountInfo<'info>, signer_seeds: &[&[&[u8]]], amount: u64) -> Result<()> {
+        transfer_from_vault(
+            self.token_program_for(self.vault_0_mint),
+            self.vault_token_0_account.clone(),
+            self.vault_0_mint,
+            to,
+            self.vault_config.to_account_info(),
+            signer_seeds,
+            amount
+        )
+    }
+
+    pub(crate) fn transfer_1(&self, to: AccountInfo<'info>, signer_seeds: &[&[&[u8]]], amount: u64) -> Result<()> {
+        transfer_from_vault(
+            self.token_program_for(self.vault_1_mint),
+            self.vault_token_1_account.clone(),
+            self.vault_1_mint,
+            to,
+            self.vault_config.to_account_info(),
+            signer_seeds,
+            amount
+        )
+    }
+}
+
+// Withholds the protocol fee, then pays the rest to the claimer or per fee share
+pub(crate) fn pay_out_fees<'info>(
+    accounts: &FeeAccounts<'_, 'info>,
+    recipient_token_0_account: AccountInfo<'info>,
+    recipient_token_1_account: AccountInfo<'info>,
+    share_recipients: &'info [AccountInfo<'info>],
+    signer_seeds: &[&[&[u8]]],
+    amount_0: u64,
+    amount_1: u64
+) -> Result<()> {
+    let (protocol_0, protocol_1) = withhold_protocol_fee(accounts, signer_seeds, amount_0, amount_1)?;
+
+    distribute_fees(
+        accounts,
+        recipient_token_0_account,
+        recipient_token_1_account,
+        share_recipients,
+        signer_seeds,
+        amount_0 - protocol_0,
+        amount_1 - protocol_1
+    )
+}
+
+// Pays the protocol's share of the collected fees, returns the amounts withheld
+pub(crate) fn withhold_protocol_fee(
+    accounts: &FeeAccounts,
+    signer_seeds: &[&[&[u8]]],
+    amount_0: u64,
+    amount_1: u64
+) -> Result<(u64, u64)> {
+    let protocol_fee_bps = accounts.vault_config.protocol_fee_bps;
+    if protocol_fee_bps == 0 {
+        return Ok((0, 0));
+    }
+
+    let (Some(protocol_token_0_account), Some(protocol_token_1_account)) = (
+        &accounts.protocol_token_0_account,
+        &accounts.protocol_token_1_account,
+    ) else {
+        return err!(VaultError::MissingProtocolFeeAccount);
+    };
+
+    let protocol_0 = bps_of(amount_0, protocol_fee_bps)?;
+    let protocol_1 = bps_of(amount_1, protocol_fee_bps)?;
+
+    accounts.transfer_0(protocol_token_0_account.clone(), signer_seeds, protocol_0)?;
+    accounts.transfer_1(protocol_token_1_account.clone(), signer_seeds, protocol_1)?;
+
+    emit!(ProtocolFeeWithheld {
+        position_nft: accounts.user_position.position_nft,
+        recipient: accounts.vault_config.protocol_fee_recipient,
+        amount_0: protocol_0,
+        amount_1: protocol_1,
+    });
+
+    Ok((protocol_0, protocol_1))
+}
+
+// Pays the collected fees out of the vault token accounts, to the claimer or per fee share.
+// With fee shares, share_recipients holds a (token_0, token_1) account pair per share, in order.
+fn distribute_fees<'info>(
+    accounts: &FeeAccounts<'_, 'info>,
+    recipient_token_0_account: AccountInfo<'info>,
+    recipient_token_1_account: AccountInfo<'info>,
+    share_recipients: &'info [AccountInfo<'info>],
+    signer_seeds: &[&[&[u8]]],
+    amount_0: u64,
+    amount_1: u64
+) -> Result<()> {
+    let fee_shares = accounts.user_position.fee_shares();
+    if fee_shares.is_empty() {
+        accounts.transfer_0(recipient_token_0_account, signer_seeds, amount_0)?;
+        accounts.transfer_1(recipient_token_1_account, signer_seeds, amount_1)?;
+        return Ok(());
+    }
+
+    require!(
+        share_recipients.len() == fee_shares.len() * 2,
+        VaultError::InvalidFeeRecipient
+    );
+
+    let (mut paid_0, mut paid_1) = (0u64, 0u64);
+    for (index, share) in fee_shares.iter().enumerate() {
+        // The last share takes the rounding remainder
+        let (share_0, share_1) = if index == fee_shares.len() - 1 {
+            (amount_0 - paid_0, amount_1 - paid_1)
+        } else {
+            (bps_of(amount_0, share.bps)?, bps_of(amount_1, share.bps)?)
+        };
+        paid_0 += share_0;
+        paid_1 += share_1;
+
+        let recipient_0 = &share_recipients[index * 2];
+        let recipient_1 = &share_recipients[index * 2 + 1];
+        check_token_account(recipient_0, &accounts.vault_0_mint.key(), &share.recipient)?;
+        check_token_account(recipient_1, &accounts.vault_1_mint.key(), &share.recipient)?;
+
+        accounts.transfer_0(recipient_0.clone(), signer_seeds, share_0)?;
+        accounts.transfer_1(recipient_1.clone(), signer_seeds, share_1)?;
+
+        emit!(FeeShareDistributed {
+            position_nft: accounts.user_position.position_nft,
+            recipient: share.recipient,
+            amount_0: share_0,
+            amount_1: share_1,
+        });
+    }
+
+    Ok(())
+}
+
+fn bps_of(amount: u64, bps: u16) -> Result<u64> {
+    Ok(
+        (((amount as u128) * (bps as u128)) / (BPS_DENOMINATOR as u128))
+            .try_into()
+            .map_err(|_| VaultError::BalanceOverflow)?
+    )
+}
+
+// Checks an unnamed account is a token account of the mint owned by owner
+pub(crate) fn check_token_account<'info>(
+    account: &'info AccountInfo<'info>,
+    mint: &Pubkey,
+    owner: &Pubkey
+) -> Result<()> {
+    let token_account = InterfaceAccount::<TokenAccount>::try_from(account)?;
+    require!(
+        token_account.mint == *mint && token_account.owner == *owner,
+        VaultError::InvalidFeeRecipient
+    );
     Ok(())
 }
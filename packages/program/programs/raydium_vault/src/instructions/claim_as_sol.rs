@@ -0,0 +1,347 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    memo::Memo,
+    token::{ spl_token::native_mint, Token },
+    token_2022::Token2022,
+    token_interface::{ self, CloseAccount, Mint, TokenAccount, TokenInterface },
+};
+use raydium_cpmm_cpi::program::RaydiumCpmm;
+use raydium_locking_cpi::{ cpi, program::RaydiumLiquidityLocking, states::LockedCpLiquidityState };
+
+use crate::{
+    constants::{ POSITION_SEED, SOL_CLAIM_SEED, VAULT_CONFIG_SEED },
+    errors::VaultError,
+    events::FeesClaimedAsSol,
+    instructions::claim::{ collect_into_vault, record_claim, withhold_protocol_fee, FeeAccounts },
+    state::{ UserPosition, VaultConfig },
+    utils::get_current_timestamp,
+};
+
+// Claim as sol instructions
+// Claims like claim, then swaps the token side into WSOL through the position's CPMM pool and pays
+// the claimer native SOL. Signed by the claimer only, since the signer picks the swap's minimum
+// output and anyone else could set it to zero and sandwich the swap. Positions with fee shares
+// claim in tokens.
+#[derive(Accounts)]
+pub struct ClaimAsSol<'info> {
+    #[account(
+        mut,
+        constraint = authority.key() == user_position.claimer.key() @VaultError::Unauthorized
+    )]
+    pub authority: Signer<'info>,
+
+    #[account(seeds = [VAULT_CONFIG_SEED], bump)]
+    pub vault_config: Box<Account<'info, VaultConfig>>,
+
+    #[account(mut, seeds = [POSITION_SEED, locked_liquidity.fee_nft_mint.key().as_ref()], bump)]
+    pub user_position: Box<Account<'info, UserPosition>>,
+
+    pub locking_program: Program<'info, RaydiumLiquidityLocking>,
+
+    /// CHECK: the authority of token vault that cp is locked
+    #[account(
+        seeds = [raydium_locking_cpi::LOCK_CP_AUTH_SEED.as_bytes()],
+        bump,
+        seeds::program = locking_program.key()
+    )]
+    pub locked_authority: UncheckedAccount<'info>,
+
+    /// Fee token account
+    #[account(
+        token::mint = locked_liquidity.fee_nft_mint,
+        token::authority = vault_config,
+        constraint = fee_nft_account.amount == 1
+    )]
+    pub fee_nft_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// Store the locked the information of liquidity
+    #[account(
+        mut,
+        constraint = locked_liquidity.fee_nft_mint == fee_nft_account.mint
+    )]
+    pub locked_liquidity: Box<Account<'info, LockedCpLiquidityState>>,
+
+    /// cpmm program
+    pub cpmm_program: Program<'info, RaydiumCpmm>,
+
+    /// CHECK: cp program vault and lp mint authority
+    #[account(
+        seeds = [raydium_cpmm_cpi::AUTH_SEED.as_bytes()],
+        bump,
+        seeds::program = cpmm_program.key()
+    )]
+    pub cp_authority: UncheckedAccount<'info>,
+
+    /// CHECK: Pool state account
+    #[account(
+        mut,
+        address = locked_liquidity.pool_id
+    )]
+    pub pool_state: UncheckedAccount<'info>,
+
+    /// CHECK: amm config of the pool, checked by the cpmm program
+    pub amm_config: UncheckedAccount<'info>,
+
+    /// CHECK: oracle observation of the pool, checked by the cpmm program
+    #[account(mut)]
+    pub observation_state: UncheckedAccount<'info>,
+
+    /// The mint of liquidity token
+    /// address = pool_state.lp_mint
+    #[account(mut)]
+    pub lp_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    /// Vault-owned token account token_0 fees are collected into
+    /// Created by the first claim on the mint
+    #[account(
+        init_if_needed,
+        payer = authority,
+        associated_token::mint = vault_0_mint,
+        associated_token::authority = vault_config,
+        associated_token::token_program = token_0_program
+    )]
+    pub vault_token_0_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// Vault-owned token account token_1 fees are collected into
+    /// Created by the first claim on the mint
+    #[account(
+        init_if_needed,
+        payer = authority,
+        associated_token::mint = vault_1_mint,
+        associated_token::authority = vault_config,
+        associated_token::token_program = token_1_program
+    )]
+    pub vault_token_1_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// Protocol fee token account for token_0, required when the protocol fee is set
+    #[account(
+        mut,
+        token::mint = token_0_vault.mint,
+        token::authority = vault_config.protocol_fee_recipient
+    )]
+    pub protocol_token_0_account: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+
+    /// Protocol fee token account for token_1, required when the protocol fee is set
+    #[account(
+        mut,
+        token::mint = token_1_vault.mint,
+        token::authority = vault_config.protocol_fee_recipient
+    )]
+    pub protocol_token_1_account: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+
+    #[account(address = native_mint::ID)]
+    pub wsol_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    /// Temporary WSOL account the claim is gathered in, closed to unwrap it
+    #[account(
+        init,
+        payer = authority,
+        seeds = [SOL_CLAIM_SEED, user_position.key().as_ref()],
+        bump,
+        token::mint = wsol_mint,
+        token::authority = vault_config,
+        token::token_program = token_program
+    )]
+    pub sol_claim_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// The address that holds pool tokens for token_0
+    /// address = pool_state.token_0_vault
+    #[account(mut)]
+    pub token_0_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// The address that holds pool tokens for token_1
+    /// address = pool_state.token_1_vault
+    #[account(mut)]
+    pub token_1_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// The mint of token_0 vault
+    #[account(address = token_0_vault.mint)]
+    pub vault_0_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    /// The mint of token_1 vault
+    #[account(address = token_1_vault.mint)]
+    pub vault_1_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    /// locked lp token account
+    #[account(
+        mut,
+        associated_token::mint = lp_mint,
+        associated_token::authority = locked_authority,
+    )]
+    pub locked_lp_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// Spl token program or token program 2022
+    pub token_program: Program<'info, Token>,
+
+    /// Token program 2022
+    pub token_program_2022: Program<'info, Token2022>,
+
+    /// Token program of the token_0 mint
+    #[account(address = *vault_0_mint.to_account_info().owner)]
+    pub token_0_program: Interface<'info, TokenInterface>,
+
+    /// Token program of the token_1 mint
+    #[account(address = *vault_1_mint.to_account_info().owner)]
+    pub token_1_program: Interface<'info, TokenInterface>,
+
+    /// memo program
+    /// CHECK:
+    pub memo_program: Program<'info, Memo>,
+
+    pub associated_token_program: Program<'info, AssociatedToken>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[inline(never)]
+pub fn process_claim_as_sol(ctx: Context<ClaimAsSol>, minimum_amount_out: u64) -> Result<()> {
+    require!(ctx.accounts.user_position.fee_share_count == 0, VaultError::FeeSharesNotSupported);
+    let sol_is_token_0 = if ctx.accounts.vault_0_mint.key() == native_mint::ID {
+        true
+    } else if ctx.accounts.vault_1_mint.key() == native_mint::ID {
+        false
+    } else {
+        return err!(VaultError::NotSolPool);
+    };
+
+    let vault_bumps: &[u8] = &[ctx.bumps.vault_config];
+    let signer_seeds: &[&[&[u8]]] = &[&[VAULT_CONFIG_SEED, vault_bumps]];
+    let current_time = get_current_timestamp()?;
+    let cpi_accounts = cpi::accounts::CollectCpFee {
+        authority: ctx.accounts.locked_authority.to_account_info(),
+        fee_nft_owner: ctx.accounts.vault_config.to_account_info(),
+        fee_nft_account: ctx.accounts.fee_nft_account.to_account_info(),
+        locked_liquidity: ctx.accounts.locked_liquidity.to_account_info(),
+        cpmm_program: ctx.accounts.cpmm_program.to_account_info(),
+        cp_authority: ctx.accounts.cp_authority.to_account_info(),
+        pool_state: ctx.accounts.pool_state.to_account_info(),
+        lp_mint: ctx.accounts.lp_mint.to_account_info(),
+        recipient_token_0_account: ctx.accounts.vault_token_0_account.to_account_info(),
+        recipient_token_1_account: ctx.accounts.vault_token_1_account.to_account_info(),
+        token_0_vault: ctx.accounts.token_0_vault.to_account_info(),
+        token_1_vault: ctx.accounts.token_1_vault.to_account_info(),
+        vault_0_mint: ctx.accounts.vault_0_mint.to_account_info(),
+        vault_1_mint: ctx.accounts.vault_1_mint.to_account_info(),
+        locked_lp_vault: ctx.accounts.locked_lp_vault.to_account_info(),
+        token_program: ctx.accounts.token_program.to_account_info(),
+        token_program_2022: ctx.accounts.token_program_2022.to_account_info(),
+        memo_program: ctx.accounts.memo_program.to_account_info(),
+    };
+    let (amount_0, amount_1) = collect_into_vault(
+        ctx.accounts.locking_program.to_account_info(),
+        cpi_accounts,
+        &mut ctx.accounts.vault_token_0_account,
+        &mut ctx.accounts.vault_token_1_account,
+        signer_seeds
+    )?;
+
+    let accounts = &ctx.accounts;
+    let fee_accounts = FeeAccounts {
+        vault_config: &accounts.vault_config,
+        user_position: &accounts.user_position,
+        vault_token_0_account: accounts.vault_token_0_account.to_account_info(),
+        vault_token_1_account: accounts.vault_token_1_account.to_account_info(),
+        vault_0_mint: &accounts.vault_0_mint,
+        vault_1_mint: &accounts.vault_1_mint,
+        protocol_token_0_account: accounts.protocol_token_0_account
+            .as_ref()
+            .map(|account| account.to_account_info()),
+        protocol_token_1_account: accounts.protocol_token_1_account
+            .as_ref()
+            .map(|account| account.to_account_info()),
+        token_program: accounts.token_program.to_account_info(),
+        token_program_2022: accounts.token_program_2022.to_account_info(),
+    };
+    let (protocol_0, protocol_1) = withhold_protocol_fee(
+        &fee_accounts,
+        signer_seeds,
+        amount_0,
+        amount_1
+    )?;
+
+    // The SOL side moves to the unwrap account as is, the token side is swapped into it
+    let sol_claim_account = accounts.sol_claim_account.to_account_info();
+    let (sol_amount, token_amount) = if sol_is_token_0 {
+        fee_accounts.transfer_0(sol_claim_account.clone(), signer_seeds, amount_0 - protocol_0)?;
+        (amount_0 - protocol_0, amount_1 - protocol_1)
+    } else {
+        fee_accounts.transfer_1(sol_claim_account.clone(), signer_seeds, amount_1 - protocol_1)?;
+        (amount_1 - protocol_1, amount_0 - protocol_0)
+    };
+
+    if token_amount > 0 {
+        let (input_token_account, input_vault, input_token_mint, output_vault, output_token_mint) =
+            if sol_is_token_0 {
+                (
+                    &accounts.vault_token_1_account,
+                    &accounts.token_1_vault,
+                    &accounts.vault_1_mint,
+                    &accounts.token_0_vault,
+                    &accounts.vault_0_mint,
+                )
+            } else {
+                (
+                    &accounts.vault_token_0_account,
+                    &accounts.token_0_vault,
+                    &accounts.vault_0_mint,
+                    &accounts.token_1_vault,
+                    &accounts.vault_1_mint,
+                )
+            };
+        let swap_accounts = raydium_cpmm_cpi::cpi::accounts::Swap {
+            payer: accounts.vault_config.to_account_info(),
+            authority: accounts.cp_authority.to_account_info(),
+            amm_config: accounts.amm_config.to_account_info(),
+            pool_state: accounts.pool_state.to_account_info(),
+            input_token_account: input_token_account.to_account_info(),
+            output_token_account: sol_claim_account.clone(),
+            input_vault: input_vault.to_account_info(),
+            output_vault: output_vault.to_account_info(),
+            input_token_program: fee_accounts.token_program_for(input_token_mint),
+            output_token_program: accounts.token_program.to_account_info(),
+            input_token_mint: input_token_mint.to_account_info(),
+            output_token_mint: output_token_mint.to_account_info(),
+            observation_state: accounts.observation_state.to_account_info(),
+        };
+        raydium_cpmm_cpi::cpi::swap_base_input(
+            CpiContext::new_with_signer(
+                accounts.cpmm_program.to_account_info(),
+                swap_accounts,
+                signer_seeds
+            ),
+            token_amount,
+            minimum_amount_out
+        )?;
+    }
+
+    ctx.accounts.sol_claim_account.reload()?;
+    let lamports_out = ctx.accounts.sol_claim_account.amount;
+
+    // Closing unwraps the WSOL straight to the claimer, who is the signer
+    token_interface::close_account(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            CloseAccount {
+                account: ctx.accounts.sol_claim_account.to_account_info(),
+                destination: ctx.accounts.authority.to_account_info(),
+                authority: ctx.accounts.vault_config.to_account_info(),
+            },
+            signer_seeds
+        )
+    )?;
+
+    let mint_0 = ctx.accounts.vault_0_mint.key();
+    let mint_1 = ctx.accounts.vault_1_mint.key();
+    record_claim(&mut ctx.accounts.user_position, mint_0, amount_0, mint_1, amount_1, current_time);
+
+    emit!(FeesClaimedAsSol {
+        claimer: ctx.accounts.user_position.claimer,
+        position_nft: ctx.accounts.user_position.position_nft,
+        token_swapped: token_amount,
+        sol_amount: lamports_out,
+        swap_amount_out: lamports_out - sol_amount,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,321 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::get_associated_token_address_with_program_id,
+    memo::Memo,
+    token::Token,
+    token_2022::Token2022,
+    token_interface::{ Mint, TokenAccount },
+};
+use raydium_cpmm_cpi::{ program::RaydiumCpmm, states::PoolState };
+use raydium_locking_cpi::{ cpi, program::RaydiumLiquidityLocking, states::LockedCpLiquidityState };
+
+use crate::{
+    constants::VAULT_CONFIG_SEED,
+    errors::VaultError,
+    events::BatchClaimed,
+    instructions::claim::{ collect_into_vault, pay_out_fees, record_claim, FeeAccounts },
+    state::{ UserPosition, VaultConfig },
+    utils::get_current_timestamp,
+};
+
+/// Fixed accounts per position group in remaining_accounts:
+/// user_position, fee_nft_account, locked_liquidity, pool_state, lp_mint,
+/// vault_token_0_account, vault_token_1_account, token_0_vault, token_1_vault,
+/// vault_0_mint, vault_1_mint, locked_lp_vault, recipient_token_0_account, recipient_token_1_account
+pub const CLAIM_GROUP_ACCOUNTS: usize = 14;
+
+// Claim many instructions
+// Collects fees for a batch of positions, one account group per position in remaining_accounts.
+// Each group is the fixed accounts above, then the protocol fee token accounts when the protocol
+// fee is set, then a (token_0, token_1) pair per fee share. The signer must be allowed to claim
+// every position, same as claim. Positions with no fees to collect are skipped before the collect
+// CPI, so one of them doesn't fail the whole batch. The vault token
+// accounts are not created here, the first claim, claim_as_sol or compound on a mint creates them,
+// or the caller can create them idempotently ahead of the batch.
+#[derive(Accounts)]
+pub struct ClaimMany<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(seeds = [VAULT_CONFIG_SEED], bump)]
+    pub vault_config: Account<'info, VaultConfig>,
+
+    pub locking_program: Program<'info, RaydiumLiquidityLocking>,
+
+    /// CHECK: the authority of token vault that cp is locked
+    #[account(
+        seeds = [raydium_locking_cpi::LOCK_CP_AUTH_SEED.as_bytes()],
+        bump,
+        seeds::program = locking_program.key()
+    )]
+    pub locked_authority: UncheckedAccount<'info>,
+
+    /// cpmm program
+    pub cpmm_program: Program<'info, RaydiumCpmm>,
+
+    /// CHECK: cp program vault and lp mint authority
+    #[account(
+        seeds = [raydium_cpmm_cpi::AUTH_SEED.as_bytes()],
+        bump,
+        seeds::program = cpmm_program.key()
+    )]
+    pub cp_authority: UncheckedAccount<'info>,
+
+    /// Spl token program or token program 2022
+    pub token_program: Program<'info, Token>,
+
+    /// Token program 2022
+    pub token_program_2022: Program<'info, Token2022>,
+
+    /// memo program
+    /// CHECK:
+    pub memo_program: Program<'info, Memo>,
+}
+
+#[inline(never)]
+pub fn process_claim_many<'a, 'b, 'c: 'info, 'info>(
+    ctx: Context<'a, 'b, 'c, 'info, ClaimMany<'info>>
+) -> Result<()> {
+    require!(!ctx.remaining_accounts.is_empty(), VaultError::InvalidPosition);
+
+    let vault_bumps: &[u8] = &[ctx.bumps.vault_config];
+    let signer_seeds: &[&[&[u8]]] = &[&[VAULT_CONFIG_SEED, vault_bumps]];
+    let current_time = get_current_timestamp()?;
+    let protocol_accounts = if ctx.accounts.vault_config.protocol_fee_bps > 0 { 2 } else { 0 };
+
+    let mut remaining: &'info [AccountInfo<'info>] = ctx.remaining_accounts;
+    let (mut claimed, mut skipped) = (0u16, 0u16);
+    while !remaining.is_empty() {
+        require!(remaining.len() >= CLAIM_GROUP_ACCOUNTS, VaultError::InvalidPosition);
+        let mut user_position = Account::<UserPosition>::try_from(&remaining[0])?;
+        let group_len =
+            CLAIM_GROUP_ACCOUNTS + protocol_accounts + (user_position.fee_share_count as usize) * 2;
+        require!(remaining.len() >= group_len, VaultError::InvalidPosition);
+
+        let (group, rest) = remaining.split_at(group_len);
+        remaining = rest;
+
+        if claim_position(ctx.accounts, &mut user_position, group, signer_seeds, current_time)? {
+            user_position.exit(&crate::ID)?;
+            claimed += 1;
+        } else {
+            skipped += 1;
+        }
+    }
+
+    emit!(BatchClaimed {
+        claimed_time: current_time,
+        claimed,
+        skipped,
+    });
+
+    Ok(())
+}
+
+// Validates and claims one position group, returns false if there was nothing to collect
+fn claim_position<'info>(
+    accounts: &ClaimMany<'info>,
+    user_position: &mut Account<'info, UserPosition>,
+    group: &'info [AccountInfo<'info>],
+    signer_seeds: &[&[&[u8]]],
+    current_time: i64
+) -> Result<bool> {
+    let vault_config = &accounts.vault_config;
+    let authority = accounts.authority.key();
+    require!(
+        authority == vault_config.executor_authority ||
+            authority == user_position.claimer ||
+            user_position.permissionless_claim,
+        VaultError::Unauthorized
+    );
+
+    let fee_nft_account = InterfaceAccount::<TokenAccount>::try_from(&group[1])?;
+    let locked_liquidity = Account::<LockedCpLiquidityState>::try_from(&group[2])?;
+    let pool_state = &group[3];
+    let lp_mint = &group[4];
+    let mut vault_token_0_account = InterfaceAccount::<TokenAccount>::try_from(&group[5])?;
+    let mut vault_token_1_account = InterfaceAccount::<TokenAccount>::try_from(&group[6])?;
+    let token_0_vault = InterfaceAccount::<TokenAccount>::try_from(&group[7])?;
+    let token_1_vault = InterfaceAccount::<TokenAccount>::try_from(&group[8])?;
+    let vault_0_mint = InterfaceAccount::<Mint>::try_from(&group[9])?;
+    let vault_1_mint = InterfaceAccount::<Mint>::try_from(&group[10])?;
+    let locked_lp_vault = &group[11];
+    let recipient_token_0_account = &group[12];
+    let recipient_token_1_account = &group[13];
+
+    // Positions are created at the PDA of their fee nft, so the stored nft ties the group together
+    require!(
+        locked_liquidity.fee_nft_mint == user_position.position_nft,
+        VaultError::InvalidPosition
+    );
+    require!(
+        fee_nft_account.mint == locked_liquidity.fee_nft_mint && fee_nft_account.amount == 1,
+        VaultError::InvalidToken
+    );
+    require!(fee_nft_account.owner == vault_config.key(), VaultError::InvalidNftOwner);
+    require!(pool_state.key() == locked_liquidity.pool_id, VaultError::InvalidPosition);
+    require!(
+        vault_0_mint.key() == token_0_vault.mint && vault_1_mint.key() == token_1_vault.mint,
+        VaultError::InvalidToken
+    );
+    require!(
+        is_associated_token_account(&group[5], &vault_config.key(), &token_0_vault.mint) &&
+            is_associated_token_account(&group[6], &vault_config.key(), &token_1_vault.mint),
+        VaultError::InvalidToken
+    );
+    require!(
+        is_associated_token_account(
+            recipient_token_0_account,
+            &user_position.claimer,
+            &token_0_vault.mint
+        ) &&
+            is_associated_token_account(
+                recipient_token_1_account,
+                &user_position.claimer,
+                &token_1_vault.mint
+            ),
+        VaultError::InvalidClaimerAddress
+    );
+
+    let (protocol_token_0_account, protocol_token_1_account, share_recipients) = if
+        vault_config.protocol_fee_bps > 0
+    {
+        let protocol_0 = InterfaceAccount::<TokenAccount>::try_from(&group[14])?;
+        let protocol_1 = InterfaceAccount::<TokenAccount>::try_from(&group[15])?;
+        require!(
+            protocol_0.mint == token_0_vault.mint &&
+                protocol_0.owner == vault_config.protocol_fee_recipient &&
+                protocol_1.mint == token_1_vault.mint &&
+                protocol_1.owner == vault_config.protocol_fee_recipient,
+            VaultError::MissingProtocolFeeAccount
+        );
+        (Some(group[14].clone()), Some(group[15].clone()), &group[16..])
+    } else {
+        (None, None, &group[CLAIM_GROUP_ACCOUNTS..])
+    };
+
+    let pending_fee_lp = {
+        let pool = AccountLoader::<PoolState>::try_from(pool_state)?;
+        let pool = pool.load()?;
+        pending_fee_lp(&locked_liquidity, &pool, token_0_vault.amount, token_1_vault.amount)
+    };
+    if pending_fee_lp == 0 {
+        return Ok(false);
+    }
+
+    let cpi_accounts = cpi::accounts::CollectCpFee {
+        authority: accounts.locked_authority.to_account_info(),
+        fee_nft_owner: vault_config.to_account_info(),
+        fee_nft_account: fee_nft_account.to_account_info(),
+        locked_liquidity: locked_liquidity.to_account_info(),
+        cpmm_program: accounts.cpmm_program.to_account_info(),
+        cp_authority: accounts.cp_authority.to_account_info(),
+        pool_state: pool_state.clone(),
+        lp_mint: lp_mint.clone(),
+        recipient_token_0_account: vault_token_0_account.to_account_info(),
+        recipient_token_1_account: vault_token_1_account.to_account_info(),
+        token_0_vault: token_0_vault.to_account_info(),
+        token_1_vault: token_1_vault.to_account_info(),
+        vault_0_mint: vault_0_mint.to_account_info(),
+        vault_1_mint: vault_1_mint.to_account_info(),
+        locked_lp_vault: locked_lp_vault.clone(),
+        token_program: accounts.token_program.to_account_info(),
+        token_program_2022: accounts.token_program_2022.to_account_info(),
+        memo_program: accounts.memo_program.to_account_info(),
+    };
+    let (amount_0, amount_1) = collect_into_vault(
+        accounts.locking_program.to_account_info(),
+        cpi_accounts,
+        &mut vault_token_0_account,
+        &mut vault_token_1_account,
+        signer_seeds
+    )?;
+
+    pay_out_fees(
+        &FeeAccounts {
+            vault_config,
+            user_position,
+            vault_token_0_account: vault_token_0_account.to_account_info(),
+            vault_token_1_account: vault_token_1_account.to_account_info(),
+            vault_0_mint: &vault_0_mint,
+            vault_1_mint: &vault_1_mint,
+            protocol_token_0_account,
+            protocol_token_1_account,
+            token_program: accounts.token_program.to_account_info(),
+            token_program_2022: accounts.token_program_2022.to_account_info(),
+        },
+        recipient_token_0_account.clone(),
+        recipient_token_1_account.clone(),
+        share_recipients,
+        signer_seeds,
+        amount_0,
+        amount_1
+    )?;
+
+    record_claim(
+        user_position,
+        vault_0_mint.key(),
+        amount_0,
+        vault_1_mint.key(),
+        amount_1,
+        current_time
+    );
+
+    Ok(true)
+}
+
+// Fee lp the locking program collects for a position. The lock records the pool's k and lp supply
+// at its last collect, swap fees since then raise sqrt(k) per lp, and the fee lp is the part of
+// the locked lp that growth is worth. Deposits and withdrawals keep sqrt(k) per lp, so they add none.
+fn pending_fee_lp(
+    locked_liquidity: &LockedCpLiquidityState,
+    pool_state: &PoolState,
+    token_0_vault_amount: u64,
+    token_1_vault_amount: u64
+) -> u64 {
+    if locked_liquidity.last_k == 0 || locked_liquidity.last_lp == 0 || pool_state.lp_supply == 0 {
+        // Nothing recorded to compare against, leave it to the collect CPI
+        return u64::MAX;
+    }
+
+    // Protocol and fund fees sit in the pool vaults but are not liquidity
+    let amount_0 = token_0_vault_amount
+        .saturating_sub(pool_state.protocol_fees_token_0)
+        .saturating_sub(pool_state.fund_fees_token_0);
+    let amount_1 = token_1_vault_amount
+        .saturating_sub(pool_state.protocol_fees_token_1)
+        .saturating_sub(pool_state.fund_fees_token_1);
+
+    // sqrt(k) / lp now against then, cross-multiplied. Each side is at most 2^64 * 2^64
+    let value_then = sqrt_u128(locked_liquidity.last_k) * (pool_state.lp_supply as u128);
+    let value_now =
+        sqrt_u128((amount_0 as u128) * (amount_1 as u128)) * (locked_liquidity.last_lp as u128);
+    let unclaimed = locked_liquidity.unclaimed_lp_amount;
+    if value_now <= value_then {
+        return unclaimed;
+    }
+
+    let fee_lp = (locked_liquidity.locked_lp_amount as u128)
+        .checked_mul(value_now - value_then)
+        .map_or(u128::MAX, |growth| growth / value_now);
+    u64::try_from(fee_lp).unwrap_or(u64::MAX).saturating_add(unclaimed)
+}
+
+// Integer square root, rounded down
+fn sqrt_u128(value: u128) -> u128 {
+    if value < 2 {
+        return value;
+    }
+    let mut x = 1u128 << ((128 - value.leading_zeros()).div_ceil(2));
+    loop {
+        let next = (x + value / x) / 2;
+        if next >= x {
+            return x;
+        }
+        x = next;
+    }
+}
+
+fn is_associated_token_account(account: &AccountInfo, wallet: &Pubkey, mint: &Pubkey) -> bool {
+    account.key() == get_associated_token_address_with_program_id(wallet, mint, account.owner)
+}
@@ -0,0 +1,487 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    memo::Memo,
+    metadata::Metadata,
+    token::{ self, CloseAccount, InitializeAccount3, Token, TokenAccount as SplTokenAccount, Transfer },
+    token_2022::Token2022,
+    token_interface::{ Mint, TokenAccount, TokenInterface },
+};
+use raydium_cpmm_cpi::program::RaydiumCpmm;
+use raydium_locking_cpi::{ cpi, program::RaydiumLiquidityLocking, states::LockedCpLiquidityState };
+
+use crate::{
+    constants::{ NFT_FAUCET_SEED, POSITION_SEED, VAULT_CONFIG_SEED },
+    errors::VaultError,
+    events::{ FeesCompounded, NftPositionDeposited },
+    instructions::claim::{ collect_into_vault, record_claim, withhold_protocol_fee, FeeAccounts },
+    state::{ UserPosition, VaultConfig },
+    utils::get_current_timestamp,
+};
+
+// Compound instructions
+// Collects a compounding position's fees and deposits them back into the same CPMM pool. The new LP
+// is locked through the locking program, and its fee nft becomes a new position in the vault under
+// the same claimer and settings. Whatever the deposit leaves over goes to the claimer.
+// Signed by the executor or the claimer, since the signer picks the deposit amounts. Positions with
+// fee shares can't compound, since the whole claim is deposited and the share holders would get nothing.
+#[derive(Accounts)]
+pub struct Compound<'info> {
+    #[account(
+        mut,
+        constraint = authority.key() == vault_config.executor_authority.key()
+            || authority.key() == user_position.claimer.key() @VaultError::Unauthorized
+    )]
+    pub authority: Signer<'info>,
+
+    #[account(seeds = [VAULT_CONFIG_SEED], bump)]
+    pub vault_config: Box<Account<'info, VaultConfig>>,
+
+    #[account(
+        mut,
+        seeds = [POSITION_SEED, locked_liquidity.fee_nft_mint.key().as_ref()],
+        bump,
+        constraint = user_position.compound @VaultError::CompoundNotEnabled
+    )]
+    pub user_position: Box<Account<'info, UserPosition>>,
+
+    pub locking_program: Program<'info, RaydiumLiquidityLocking>,
+
+    /// CHECK: the authority of token vault that cp is locked
+    #[account(
+        seeds = [raydium_locking_cpi::LOCK_CP_AUTH_SEED.as_bytes()],
+        bump,
+        seeds::program = locking_program.key()
+    )]
+    pub locked_authority: UncheckedAccount<'info>,
+
+    /// Fee token account
+    #[account(
+        token::mint = locked_liquidity.fee_nft_mint,
+        token::authority = vault_config,
+        constraint = fee_nft_account.amount == 1
+    )]
+    pub fee_nft_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// Store the locked the information of liquidity
+    #[account(
+        mut,
+        constraint = locked_liquidity.fee_nft_mint == fee_nft_account.mint
+    )]
+    pub locked_liquidity: Box<Account<'info, LockedCpLiquidityState>>,
+
+    /// cpmm program
+    pub cpmm_program: Program<'info, RaydiumCpmm>,
+
+    /// CHECK: cp program vault and lp mint authority
+    #[account(
+        seeds = [raydium_cpmm_cpi::AUTH_SEED.as_bytes()],
+        bump,
+        seeds::program = cpmm_program.key()
+    )]
+    pub cp_authority: UncheckedAccount<'info>,
+
+    /// CHECK: Pool state account
+    #[account(
+        mut,
+        address = locked_liquidity.pool_id
+    )]
+    pub pool_state: UncheckedAccount<'info>,
+
+    /// The mint of liquidity token
+    /// address = pool_state.lp_mint
+    #[account(mut)]
+    pub lp_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    /// The claimer's token_0 account, receives what the deposit leaves over
+    #[account(
+        mut,
+        associated_token::mint = token_0_vault.mint,
+        associated_token::authority = user_position.claimer
+    )]
+    pub recipient_token_0_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// The claimer's token_1 account, receives what the deposit leaves over
+    #[account(
+        mut,
+        associated_token::mint = token_1_vault.mint,
+        associated_token::authority = user_position.claimer
+    )]
+    pub recipient_token_1_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// Vault-owned token account token_0 fees are collected into, then deposited
+    /// Created by the first claim on the mint
+    #[account(
+        init_if_needed,
+        payer = authority,
+        associated_token::mint = vault_0_mint,
+        associated_token::authority = vault_config,
+        associated_token::token_program = token_0_program
+    )]
+    pub vault_token_0_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// Vault-owned token account token_1 fees are collected into, then deposited
+    /// Created by the first claim on the mint
+    #[account(
+        init_if_needed,
+        payer = authority,
+        associated_token::mint = vault_1_mint,
+        associated_token::authority = vault_config,
+        associated_token::token_program = token_1_program
+    )]
+    pub vault_token_1_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// Vault-owned lp token account the deposit mints into, then locked
+    /// Created by the first compound on the pool
+    #[account(
+        init_if_needed,
+        payer = authority,
+        associated_token::mint = lp_mint,
+        associated_token::authority = vault_config,
+        associated_token::token_program = token_program
+    )]
+    pub vault_lp_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// Protocol fee token account for token_0, required when the protocol fee is set
+    #[account(
+        mut,
+        token::mint = token_0_vault.mint,
+        token::authority = vault_config.protocol_fee_recipient
+    )]
+    pub protocol_token_0_account: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+
+    /// Protocol fee token account for token_1, required when the protocol fee is set
+    #[account(
+        mut,
+        token::mint = token_1_vault.mint,
+        token::authority = vault_config.protocol_fee_recipient
+    )]
+    pub protocol_token_1_account: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+
+    /// The address that holds pool tokens for token_0
+    /// address = pool_state.token_0_vault
+    #[account(mut)]
+    pub token_0_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// The address that holds pool tokens for token_1
+    /// address = pool_state.token_1_vault
+    #[account(mut)]
+    pub token_1_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// The mint of token_0 vault
+    #[account(address = token_0_vault.mint)]
+    pub vault_0_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    /// The mint of token_1 vault
+    #[account(address = token_1_vault.mint)]
+    pub vault_1_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    /// locked lp token account
+    #[account(
+        mut,
+        associated_token::mint = lp_mint,
+        associated_token::authority = locked_authority,
+    )]
+    pub locked_lp_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// Fresh keypair the locking program creates the new fee nft at
+    #[account(mut)]
+    pub new_fee_nft_mint: Signer<'info>,
+
+    /// CHECK: vault_config's associated account for the new fee nft, created by the locking program
+    #[account(mut)]
+    pub new_fee_nft_account: UncheckedAccount<'info>,
+
+    /// CHECK: locked liquidity state of the new lock, created by the locking program
+    #[account(mut)]
+    pub new_locked_liquidity: UncheckedAccount<'info>,
+
+    /// CHECK: metadata of the new fee nft, unused since the nft is locked without metadata
+    #[account(mut)]
+    pub new_metadata_account: UncheckedAccount<'info>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + std::mem::size_of::<UserPosition>(),
+        seeds = [POSITION_SEED, new_fee_nft_mint.key().as_ref()],
+        bump
+    )]
+    pub new_user_position: Box<Account<'info, UserPosition>>,
+
+    /// CHECK: faucet the new fee nft is deposited into, created once the nft exists
+    #[account(mut, seeds = [NFT_FAUCET_SEED, new_fee_nft_mint.key().as_ref()], bump)]
+    pub new_nft_token_faucet: UncheckedAccount<'info>,
+
+    /// Spl token program or token program 2022
+    pub token_program: Program<'info, Token>,
+
+    /// Token program 2022
+    pub token_program_2022: Program<'info, Token2022>,
+
+    /// Token program of the token_0 mint
+    #[account(address = *vault_0_mint.to_account_info().owner)]
+    pub token_0_program: Interface<'info, TokenInterface>,
+
+    /// Token program of the token_1 mint
+    #[account(address = *vault_1_mint.to_account_info().owner)]
+    pub token_1_program: Interface<'info, TokenInterface>,
+
+    /// memo program
+    /// CHECK:
+    pub memo_program: Program<'info, Memo>,
+
+    pub associated_token_program: Program<'info, AssociatedToken>,
+
+    pub metadata_program: Program<'info, Metadata>,
+
+    pub system_program: Program<'info, System>,
+
+    pub rent: Sysvar<'info, Rent>,
+}
+
+#[inline(never)]
+pub fn process_compound(
+    ctx: Context<Compound>,
+    lp_token_amount: u64,
+    maximum_token_0_amount: u64,
+    maximum_token_1_amount: u64
+) -> Result<()> {
+    require!(ctx.accounts.user_position.fee_share_count == 0, VaultError::FeeSharesNotSupported);
+    let vault_bumps: &[u8] = &[ctx.bumps.vault_config];
+    let signer_seeds: &[&[&[u8]]] = &[&[VAULT_CONFIG_SEED, vault_bumps]];
+    let current_time = get_current_timestamp()?;
+    let cpi_accounts = cpi::accounts::CollectCpFee {
+        authority: ctx.accounts.locked_authority.to_account_info(),
+        fee_nft_owner: ctx.accounts.vault_config.to_account_info(),
+        fee_nft_account: ctx.accounts.fee_nft_account.to_account_info(),
+        locked_liquidity: ctx.accounts.locked_liquidity.to_account_info(),
+        cpmm_program: ctx.accounts.cpmm_program.to_account_info(),
+        cp_authority: ctx.accounts.cp_authority.to_account_info(),
+        pool_state: ctx.accounts.pool_state.to_account_info(),
+        lp_mint: ctx.accounts.lp_mint.to_account_info(),
+        recipient_token_0_account: ctx.accounts.vault_token_0_account.to_account_info(),
+        recipient_token_1_account: ctx.accounts.vault_token_1_account.to_account_info(),
+        token_0_vault: ctx.accounts.token_0_vault.to_account_info(),
+        token_1_vault: ctx.accounts.token_1_vault.to_account_info(),
+        vault_0_mint: ctx.accounts.vault_0_mint.to_account_info(),
+        vault_1_mint: ctx.accounts.vault_1_mint.to_account_info(),
+        locked_lp_vault: ctx.accounts.locked_lp_vault.to_account_info(),
+        token_program: ctx.accounts.token_program.to_account_info(),
+        token_program_2022: ctx.accounts.token_program_2022.to_account_info(),
+        memo_program: ctx.accounts.memo_program.to_account_info(),
+    };
+    let (amount_0, amount_1) = collect_into_vault(
+        ctx.accounts.locking_program.to_account_info(),
+        cpi_accounts,
+        &mut ctx.accounts.vault_token_0_account,
+        &mut ctx.accounts.vault_token_1_account,
+        signer_seeds
+    )?;
+
+    let accounts = &ctx.accounts;
+    let (protocol_0, protocol_1) = withhold_protocol_fee(
+        &fee_accounts(accounts),
+        signer_seeds,
+        amount_0,
+        amount_1
+    )?;
+    let available_0 = amount_0 - protocol_0;
+    let available_1 = amount_1 - protocol_1;
+    require!(available_0 > 0 || available_1 > 0, VaultError::NoFeesToCompound);
+    // Only this claim's fees may be deposited, not other balances in the vault accounts
+    require!(
+        maximum_token_0_amount <= available_0 && maximum_token_1_amount <= available_1,
+        VaultError::InsufficientBalance
+    );
+
+    let balance_0_before = accounts.vault_token_0_account.amount;
+    let balance_1_before = accounts.vault_token_1_account.amount;
+    let lp_before = accounts.vault_lp_account.amount;
+
+    raydium_cpmm_cpi::cpi::deposit(
+        CpiContext::new_with_signer(
+            accounts.cpmm_program.to_account_info(),
+            raydium_cpmm_cpi::cpi::accounts::Deposit {
+                owner: accounts.vault_config.to_account_info(),
+                authority: accounts.cp_authority.to_account_info(),
+                pool_state: accounts.pool_state.to_account_info(),
+                owner_lp_token: accounts.vault_lp_account.to_account_info(),
+                token_0_account: accounts.vault_token_0_account.to_account_info(),
+                token_1_account: accounts.vault_token_1_account.to_account_info(),
+                token_0_vault: accounts.token_0_vault.to_account_info(),
+                token_1_vault: accounts.token_1_vault.to_account_info(),
+                token_program: accounts.token_program.to_account_info(),
+                token_program_2022: accounts.token_program_2022.to_account_info(),
+                vault_0_mint: accounts.vault_0_mint.to_account_info(),
+                vault_1_mint: accounts.vault_1_mint.to_account_info(),
+                lp_mint: accounts.lp_mint.to_account_info(),
+            },
+            signer_seeds
+        ),
+        lp_token_amount,
+        maximum_token_0_amount,
+        maximum_token_1_amount
+    )?;
+
+    ctx.accounts.vault_token_0_account.reload()?;
+    ctx.accounts.vault_token_1_account.reload()?;
+    ctx.accounts.vault_lp_account.reload()?;
+    let deposited_0 = balance_0_before.saturating_sub(ctx.accounts.vault_token_0_account.amount);
+    let deposited_1 = balance_1_before.saturating_sub(ctx.accounts.vault_token_1_account.amount);
+    let lp_amount = ctx.accounts.vault_lp_account.amount.saturating_sub(lp_before);
+
+    let accounts = &ctx.accounts;
+    let fee_accounts = fee_accounts(accounts);
+    fee_accounts.transfer_0(
+        accounts.recipient_token_0_account.to_account_info(),
+        signer_seeds,
+        available_0 - deposited_0
+    )?;
+    fee_accounts.transfer_1(
+        accounts.recipient_token_1_account.to_account_info(),
+        signer_seeds,
+        available_1 - deposited_1
+    )?;
+
+    // The locking program mints the new fee nft to vault_config's associated account
+    cpi::lock_cp_liquidity(
+        CpiContext::new_with_signer(
+            accounts.locking_program.to_account_info(),
+            cpi::accounts::LockCpLiquidity {
+                authority: accounts.locked_authority.to_account_info(),
+                payer: accounts.authority.to_account_info(),
+                liquidity_owner: accounts.vault_config.to_account_info(),
+                fee_nft_owner: accounts.vault_config.to_account_info(),
+                fee_nft_mint: accounts.new_fee_nft_mint.to_account_info(),
+                fee_nft_account: accounts.new_fee_nft_account.to_account_info(),
+                pool_state: accounts.pool_state.to_account_info(),
+                locked_liquidity: accounts.new_locked_liquidity.to_account_info(),
+                lp_mint: accounts.lp_mint.to_account_info(),
+                liquidity_owner_lp: accounts.vault_lp_account.to_account_info(),
+                locked_lp_vault: accounts.locked_lp_vault.to_account_info(),
+                token_0_vault: accounts.token_0_vault.to_account_info(),
+                token_1_vault: accounts.token_1_vault.to_account_info(),
+                metadata_account: accounts.new_metadata_account.to_account_info(),
+                rent: accounts.rent.to_account_info(),
+                system_program: accounts.system_program.to_account_info(),
+                token_program: accounts.token_program.to_account_info(),
+                associated_token_program: accounts.associated_token_program.to_account_info(),
+                metadata_program: accounts.metadata_program.to_account_info(),
+            },
+            signer_seeds
+        ),
+        lp_amount,
+        false
+    )?;
+
+    deposit_new_fee_nft(accounts, ctx.bumps.new_nft_token_faucet, signer_seeds)?;
+
+    let position = &ctx.accounts.user_position;
+    let new_position_nft = ctx.accounts.new_fee_nft_mint.key();
+    let new_position = &mut ctx.accounts.new_user_position;
+    (new_position.claimer, new_position.position_nft, new_position.created_at, new_position.amount) = (
+        position.claimer,
+        new_position_nft,
+        current_time,
+        1,
+    );
+    new_position.last_updated = current_time;
+    new_position.permissionless_claim = position.permissionless_claim;
+    new_position.compound = true;
+
+    emit!(NftPositionDeposited {
+        position_nft: new_position_nft,
+        claimer: position.claimer,
+    });
+    emit!(FeesCompounded {
+        claimer: position.claimer,
+        position_nft: position.position_nft,
+        new_position_nft,
+        amount_0: deposited_0,
+        amount_1: deposited_1,
+        lp_amount,
+    });
+
+    let mint_0 = ctx.accounts.vault_0_mint.key();
+    let mint_1 = ctx.accounts.vault_1_mint.key();
+    record_claim(&mut ctx.accounts.user_position, mint_0, amount_0, mint_1, amount_1, current_time);
+
+    Ok(())
+}
+
+fn fee_accounts<'a, 'info>(accounts: &'a Compound<'info>) -> FeeAccounts<'a, 'info> {
+    FeeAccounts {
+        vault_config: &accounts.vault_config,
+        user_position: &accounts.user_position,
+        vault_token_0_account: accounts.vault_token_0_account.to_account_info(),
+        vault_token_1_account: accounts.vault_token_1_account.to_account_info(),
+        vault_0_mint: &accounts.vault_0_mint,
+        vault_1_mint: &accounts.vault_1_mint,
+        protocol_token_0_account: accounts.protocol_token_0_account
+            .as_ref()
+            .map(|account| account.to_account_info()),
+        protocol_token_1_account: accounts.protocol_token_1_account
+            .as_ref()
+            .map(|account| account.to_account_info()),
+        token_program: accounts.token_program.to_account_info(),
+        token_program_2022: accounts.token_program_2022.to_account_info(),
+    }
+}
+
+// Moves the new fee nft into its faucet, where deposit keeps position nfts, and closes the
+// associated account the locking program minted it to
+fn deposit_new_fee_nft<'info>(
+    accounts: &Compound<'info>,
+    faucet_bump: u8,
+    signer_seeds: &[&[&[u8]]]
+) -> Result<()> {
+    let new_fee_nft_mint = accounts.new_fee_nft_mint.key();
+    let faucet_bumps: &[u8] = &[faucet_bump];
+    let faucet_seeds: &[&[&[u8]]] = &[&[NFT_FAUCET_SEED, new_fee_nft_mint.as_ref(), faucet_bumps]];
+
+    anchor_lang::system_program::create_account(
+        CpiContext::new_with_signer(
+            accounts.system_program.to_account_info(),
+            anchor_lang::system_program::CreateAccount {
+                from: accounts.authority.to_account_info(),
+                to: accounts.new_nft_token_faucet.to_account_info(),
+            },
+            faucet_seeds
+        ),
+        Rent::get()?.minimum_balance(SplTokenAccount::LEN),
+        SplTokenAccount::LEN as u64,
+        &accounts.token_program.key()
+    )?;
+    token::initialize_account3(
+        CpiContext::new(accounts.token_program.to_account_info(), InitializeAccount3 {
+            account: accounts.new_nft_token_faucet.to_account_info(),
+            mint: accounts.new_fee_nft_mint.to_account_info(),
+            authority: accounts.vault_config.to_account_info(),
+        })
+    )?;
+
+    token::transfer(
+        CpiContext::new_with_signer(
+            accounts.token_program.to_account_info(),
+            Transfer {
+                from: accounts.new_fee_nft_account.to_account_info(),
+                to: accounts.new_nft_token_faucet.to_account_info(),
+                authority: accounts.vault_config.to_account_info(),
+            },
+            signer_seeds
+        ),
+        1 // Amount is 1 for NFTs
+    )?;
+    token::close_account(
+        CpiContext::new_with_signer(
+            accounts.token_program.to_account_info(),
+            CloseAccount {
+                account: accounts.new_fee_nft_account.to_account_info(),
+                destination: accounts.authority.to_account_info(),
+                authority: accounts.vault_config.to_account_info(),
+            },
+            signer_seeds
+        )
+    )
+}
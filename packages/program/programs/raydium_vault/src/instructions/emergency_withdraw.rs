@@ -2,16 +2,113 @@ use anchor_lang::prelude::*;
 use anchor_spl::token::{ Token, TokenAccount, Transfer };
 
 use crate::{
-    constants::{ NFT_FAUCET_SEED, POSITION_SEED, VAULT_CONFIG_SEED },
+    constants::{ EMERGENCY_WITHDRAW_SEED, NFT_FAUCET_SEED, POSITION_SEED, VAULT_CONFIG_SEED },
     errors::VaultError,
-    events::EmergencyWithdrawed,
-    state::{ UserPosition, VaultConfig },
+    events::{
+        EmergencyWithdrawCancelled,
+        EmergencyWithdrawRequested,
+        EmergencyWithdrawVetoed,
+        EmergencyWithdrawed,
+    },
+    state::{ EmergencyWithdrawRequest, UserPosition, VaultConfig },
     utils::get_current_timestamp,
 };
 
 // Emergency Withdraw instructions
+// Two-phase: the emergency authority requests a withdraw to a token account of the claimer or of
+// the manager-approved recovery address, and executes it once the delay has passed. The claimer
+// can veto the request until then, after which no new request can be made for another delay. The
+// emergency authority can cancel its own request at any time, which starts no cooldown.
 #[derive(Accounts)]
-pub struct EmergencyWithdraw<'info> {
+pub struct RequestEmergencyWithdraw<'info> {
+    #[account(mut, constraint = authority.key() == vault_config.emergency_authority @VaultError::Unauthorized)]
+    pub authority: Signer<'info>,
+
+    #[account(seeds = [VAULT_CONFIG_SEED], bump)]
+    pub vault_config: Account<'info, VaultConfig>,
+
+    #[account(
+        seeds = [POSITION_SEED, position_nft.key().as_ref()],
+        bump,
+        constraint = user_position.amount > 0 @VaultError::InsufficientBalance
+    )]
+    pub user_position: Account<'info, UserPosition>,
+
+    /// CHECK: position nft token address
+    pub position_nft: AccountInfo<'info>,
+
+    /// Receive token account, owned by the claimer or the recovery address
+    #[account(
+        constraint = is_allowed_destination(&vault_config, &user_position, &to_account)
+            @VaultError::InvalidWithdrawDestination
+    )]
+    pub to_account: Account<'info, TokenAccount>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + std::mem::size_of::<EmergencyWithdrawRequest>(),
+        seeds = [EMERGENCY_WITHDRAW_SEED, position_nft.key().as_ref()],
+        bump
+    )]
+    pub withdraw_request: Account<'info, EmergencyWithdrawRequest>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct VetoEmergencyWithdraw<'info> {
+    #[account(constraint = claimer.key() == user_position.claimer.key() @VaultError::Unauthorized)]
+    pub claimer: Signer<'info>,
+
+    #[account(mut, seeds = [POSITION_SEED, position_nft.key().as_ref()], bump)]
+    pub user_position: Account<'info, UserPosition>,
+
+    /// CHECK: position nft token address
+    pub position_nft: AccountInfo<'info>,
+
+    #[account(
+        mut,
+        seeds = [EMERGENCY_WITHDRAW_SEED, position_nft.key().as_ref()],
+        bump,
+        close = requester
+    )]
+    pub withdraw_request: Account<'info, EmergencyWithdrawRequest>,
+
+    /// CHECK: refunded the request's rent
+    #[account(mut, address = withdraw_request.requester)]
+    pub requester: AccountInfo<'info>,
+}
+
+#[derive(Accounts)]
+pub struct CancelEmergencyWithdraw<'info> {
+    #[account(constraint = authority.key() == vault_config.emergency_authority @VaultError::Unauthorized)]
+    pub authority: Signer<'info>,
+
+    #[account(seeds = [VAULT_CONFIG_SEED], bump)]
+    pub vault_config: Account<'info, VaultConfig>,
+
+    #[account(seeds = [POSITION_SEED, position_nft.key().as_ref()], bump)]
+    pub user_position: Account<'info, UserPosition>,
+
+    /// CHECK: position nft token address
+    pub position_nft: AccountInfo<'info>,
+
+    #[account(
+        mut,
+        seeds = [EMERGENCY_WITHDRAW_SEED, position_nft.key().as_ref()],
+        bump,
+        close = requester
+    )]
+    pub withdraw_request: Account<'info, EmergencyWithdrawRequest>,
+
+    /// CHECK: refunded the request's rent
+    #[account(mut, address = withdraw_request.requester)]
+    pub requester: AccountInfo<'info>,
+}
+
+#[derive(Accounts)]
+pub struct ExecuteEmergencyWithdraw<'info> {
     #[account(mut, constraint = authority.key() == vault_config.emergency_authority @VaultError::Unauthorized)]
     pub authority: Signer<'info>,
 
@@ -28,19 +125,111 @@ pub struct EmergencyWithdraw<'info> {
     #[account(mut, seeds = [NFT_FAUCET_SEED, position_nft.key().as_ref()], bump)]
     pub nft_token_faucet: Account<'info, TokenAccount>,
 
-    /// CHECK: receive token account address
-    #[account(mut)]
-    pub to_account: AccountInfo<'info>,
+    /// Receive token account recorded in the request, checked again in case the claimer or the
+    /// recovery address changed since
+    #[account(
+        mut,
+        address = withdraw_request.destination,
+        constraint = is_allowed_destination(&vault_config, &user_position, &to_account)
+            @VaultError::InvalidWithdrawDestination
+    )]
+    pub to_account: Account<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        seeds = [EMERGENCY_WITHDRAW_SEED, position_nft.key().as_ref()],
+        bump,
+        close = requester
+    )]
+    pub withdraw_request: Account<'info, EmergencyWithdrawRequest>,
+
+    /// CHECK: refunded the request's rent
+    #[account(mut, address = withdraw_request.requester)]
+    pub requester: AccountInfo<'info>,
 
     pub token_program: Program<'info, Token>,
 }
 
-pub fn process_emergency_withdraw(ctx: Context<EmergencyWithdraw>) -> Result<()> {
+fn is_allowed_destination(
+    vault_config: &VaultConfig,
+    user_position: &UserPosition,
+    to_account: &TokenAccount
+) -> bool {
+    to_account.mint == user_position.position_nft &&
+        (to_account.owner == user_position.claimer ||
+            (vault_config.recovery_address != Pubkey::default() &&
+                to_account.owner == vault_config.recovery_address))
+}
+
+pub fn process_request_emergency_withdraw(
+    ctx: Context<RequestEmergencyWithdraw>,
+    reason: u16
+) -> Result<()> {
+    let current_time = get_current_timestamp()?;
+    let delay = ctx.accounts.vault_config.emergency_withdraw_delay;
+    let last_vetoed_at = ctx.accounts.user_position.last_vetoed_at;
+    require!(
+        last_vetoed_at == 0 ||
+            current_time >=
+                last_vetoed_at.checked_add(delay).ok_or(VaultError::BalanceOverflow)?,
+        VaultError::VetoCooldown
+    );
+    let executable_at = current_time.checked_add(delay).ok_or(VaultError::BalanceOverflow)?;
+
+    let request = &mut ctx.accounts.withdraw_request;
+    request.position_nft = ctx.accounts.position_nft.key();
+    request.destination = ctx.accounts.to_account.key();
+    request.reason = reason;
+    request.requester = ctx.accounts.authority.key();
+    request.requested_at = current_time;
+    request.executable_at = executable_at;
+
+    emit!(EmergencyWithdrawRequested {
+        claimer: ctx.accounts.user_position.claimer,
+        position_nft: request.position_nft,
+        destination: request.destination,
+        reason,
+        executable_at,
+    });
+
+    Ok(())
+}
+
+pub fn process_veto_emergency_withdraw(ctx: Context<VetoEmergencyWithdraw>) -> Result<()> {
+    let current_time = get_current_timestamp()?;
+    require!(
+        current_time < ctx.accounts.withdraw_request.executable_at,
+        VaultError::VetoWindowClosed
+    );
+    ctx.accounts.user_position.last_vetoed_at = current_time;
+
+    emit!(EmergencyWithdrawVetoed {
+        claimer: ctx.accounts.claimer.key(),
+        position_nft: ctx.accounts.position_nft.key(),
+    });
+
+    Ok(())
+}
+
+pub fn process_cancel_emergency_withdraw(ctx: Context<CancelEmergencyWithdraw>) -> Result<()> {
+    emit!(EmergencyWithdrawCancelled {
+        claimer: ctx.accounts.user_position.claimer,
+        position_nft: ctx.accounts.position_nft.key(),
+    });
+
+    Ok(())
+}
+
+pub fn process_execute_emergency_withdraw(ctx: Context<ExecuteEmergencyWithdraw>) -> Result<()> {
+    let current_time = get_current_timestamp()?;
+    require!(
+        current_time >= ctx.accounts.withdraw_request.executable_at,
+        VaultError::EmergencyWithdrawPending
+    );
+
     ctx.accounts.user_position.amount = ctx.accounts.user_position.amount
         .checked_sub(1)
         .ok_or(VaultError::InsufficientBalance)?;
-
-    let current_time = get_current_timestamp()?;
     ctx.accounts.user_position.last_updated = current_time;
 
     let program_id = *ctx.program_id;
@@ -57,7 +246,7 @@ pub fn process_emergency_withdraw(ctx: Context<EmergencyWithdraw>) -> Result<()>
         &[VAULT_CONFIG_SEED, vault_bumps],
     ];
 
-    // Transfer the NFT to the vault
+    // Transfer the NFT out of the vault
     anchor_spl::token::transfer(
         CpiContext::new_with_signer(
             ctx.accounts.token_program.to_account_info(),
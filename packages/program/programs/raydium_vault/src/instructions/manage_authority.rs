@@ -1,9 +1,19 @@
 use anchor_lang::prelude::*;
 use crate::errors::VaultError;
 use crate::constants::*;
-use crate::events::{ EmergencyChanged, ExecutorChanged, ManagerChanged };
+use crate::events::{
+    AuthorityNominated,
+    AuthorityNominationCancelled,
+    EmergencyChanged,
+    EmergencyWithdrawConfigChanged,
+    ExecutorChanged,
+    ManagerChanged,
+    ProtocolFeeChanged,
+};
 use crate::state::*;
 
+// Authority changes are two-step: the current holder nominates, the nominee accepts by signing.
+// The current holder can cancel a nomination until it is accepted.
 #[derive(Accounts)]
 pub struct UpdateExecutor<'info> {
     #[account(mut, constraint = authority.key() == vault_config.manager_authority.key() @VaultError::Unauthorized)]
@@ -31,40 +41,185 @@ pub struct UpdateManager<'info> {
     pub vault_config: Account<'info, VaultConfig>,
 }
 
-pub fn change_executor_authority(ctx: Context<UpdateExecutor>, new_address: Pubkey) -> Result<()> {
+#[derive(Accounts)]
+pub struct AcceptExecutor<'info> {
+    #[account(mut, constraint = new_authority.key() == vault_config.pending_executor_authority.key() @VaultError::Unauthorized)]
+    pub new_authority: Signer<'info>,
+
+    #[account(mut, seeds = [VAULT_CONFIG_SEED], bump)]
+    pub vault_config: Account<'info, VaultConfig>,
+}
+
+#[derive(Accounts)]
+pub struct AcceptEmergency<'info> {
+    #[account(mut, constraint = new_authority.key() == vault_config.pending_emergency_authority.key() @VaultError::Unauthorized)]
+    pub new_authority: Signer<'info>,
+
+    #[account(mut, seeds = [VAULT_CONFIG_SEED], bump)]
+    pub vault_config: Account<'info, VaultConfig>,
+}
+
+#[derive(Accounts)]
+pub struct AcceptManager<'info> {
+    #[account(mut, constraint = new_authority.key() == vault_config.pending_manager_authority.key() @VaultError::Unauthorized)]
+    pub new_authority: Signer<'info>,
+
+    #[account(mut, seeds = [VAULT_CONFIG_SEED], bump)]
+    pub vault_config: Account<'info, VaultConfig>,
+}
+
+pub fn nominate_executor_authority(ctx: Context<UpdateExecutor>, new_address: Pubkey) -> Result<()> {
+    ctx.accounts.vault_config.pending_executor_authority = new_address;
+
+    emit!(AuthorityNominated {
+        role: AuthorityRole::Executor,
+        current: ctx.accounts.vault_config.executor_authority,
+        nominee: new_address,
+    });
+
+    Ok(())
+}
+
+pub fn accept_executor_authority(ctx: Context<AcceptExecutor>) -> Result<()> {
     let old_executor = ctx.accounts.vault_config.executor_authority;
-    ctx.accounts.vault_config.executor_authority = new_address;
+    ctx.accounts.vault_config.executor_authority = ctx.accounts.new_authority.key();
+    ctx.accounts.vault_config.pending_executor_authority = Pubkey::default();
 
     emit!(ExecutorChanged {
         old_executor,
-        new_executor: new_address,
+        new_executor: ctx.accounts.new_authority.key(),
     });
 
     Ok(())
 }
 
-pub fn change_emergency_authority(
+pub fn cancel_executor_nomination(ctx: Context<UpdateExecutor>) -> Result<()> {
+    let nominee = ctx.accounts.vault_config.pending_executor_authority;
+    require!(nominee != Pubkey::default(), VaultError::NoPendingNomination);
+    ctx.accounts.vault_config.pending_executor_authority = Pubkey::default();
+
+    emit!(AuthorityNominationCancelled {
+        role: AuthorityRole::Executor,
+        nominee,
+    });
+
+    Ok(())
+}
+
+pub fn nominate_emergency_authority(
     ctx: Context<UpdateEmergency>,
     new_address: Pubkey
 ) -> Result<()> {
+    ctx.accounts.vault_config.pending_emergency_authority = new_address;
+
+    emit!(AuthorityNominated {
+        role: AuthorityRole::Emergency,
+        current: ctx.accounts.vault_config.emergency_authority,
+        nominee: new_address,
+    });
+
+    Ok(())
+}
+
+pub fn accept_emergency_authority(ctx: Context<AcceptEmergency>) -> Result<()> {
     let old_emergency = ctx.accounts.vault_config.emergency_authority;
-    ctx.accounts.vault_config.emergency_authority = new_address;
+    ctx.accounts.vault_config.emergency_authority = ctx.accounts.new_authority.key();
+    ctx.accounts.vault_config.pending_emergency_authority = Pubkey::default();
 
     emit!(EmergencyChanged {
         old_emergency,
-        new_emergency: new_address.key(),
+        new_emergency: ctx.accounts.new_authority.key(),
+    });
+
+    Ok(())
+}
+
+pub fn cancel_emergency_nomination(ctx: Context<UpdateEmergency>) -> Result<()> {
+    let nominee = ctx.accounts.vault_config.pending_emergency_authority;
+    require!(nominee != Pubkey::default(), VaultError::NoPendingNomination);
+    ctx.accounts.vault_config.pending_emergency_authority = Pubkey::default();
+
+    emit!(AuthorityNominationCancelled {
+        role: AuthorityRole::Emergency,
+        nominee,
+    });
+
+    Ok(())
+}
+
+pub fn nominate_manager_authority(ctx: Context<UpdateManager>, new_address: Pubkey) -> Result<()> {
+    ctx.accounts.vault_config.pending_manager_authority = new_address;
+
+    emit!(AuthorityNominated {
+        role: AuthorityRole::Manager,
+        current: ctx.accounts.vault_config.manager_authority,
+        nominee: new_address,
     });
 
     Ok(())
 }
 
-pub fn change_manager_authority(ctx: Context<UpdateManager>, new_address: Pubkey) -> Result<()> {
+pub fn accept_manager_authority(ctx: Context<AcceptManager>) -> Result<()> {
     let old_manager = ctx.accounts.vault_config.manager_authority;
-    ctx.accounts.vault_config.manager_authority = new_address;
+    ctx.accounts.vault_config.manager_authority = ctx.accounts.new_authority.key();
+    ctx.accounts.vault_config.pending_manager_authority = Pubkey::default();
 
     emit!(ManagerChanged {
         old_manager,
-        new_manager: new_address.key(),
+        new_manager: ctx.accounts.new_authority.key(),
+    });
+
+    Ok(())
+}
+
+pub fn cancel_manager_nomination(ctx: Context<UpdateManager>) -> Result<()> {
+    let nominee = ctx.accounts.vault_config.pending_manager_authority;
+    require!(nominee != Pubkey::default(), VaultError::NoPendingNomination);
+    ctx.accounts.vault_config.pending_manager_authority = Pubkey::default();
+
+    emit!(AuthorityNominationCancelled {
+        role: AuthorityRole::Manager,
+        nominee,
+    });
+
+    Ok(())
+}
+
+// Protocol cut of claimed fees, set by the manager
+pub fn set_protocol_fee(
+    ctx: Context<UpdateManager>,
+    protocol_fee_bps: u16,
+    protocol_fee_recipient: Pubkey
+) -> Result<()> {
+    require!(protocol_fee_bps <= MAX_PROTOCOL_FEE_BPS, VaultError::ProtocolFeeTooHigh);
+
+    ctx.accounts.vault_config.protocol_fee_bps = protocol_fee_bps;
+    ctx.accounts.vault_config.protocol_fee_recipient = protocol_fee_recipient;
+
+    emit!(ProtocolFeeChanged {
+        protocol_fee_bps,
+        protocol_fee_recipient,
+    });
+
+    Ok(())
+}
+
+pub fn set_emergency_withdraw_config(
+    ctx: Context<UpdateManager>,
+    emergency_withdraw_delay: i64,
+    recovery_address: Pubkey
+) -> Result<()> {
+    require!(
+        (MIN_EMERGENCY_WITHDRAW_DELAY..=MAX_EMERGENCY_WITHDRAW_DELAY).contains(&emergency_withdraw_delay),
+        VaultError::InvalidEmergencyWithdrawDelay
+    );
+
+    ctx.accounts.vault_config.emergency_withdraw_delay = emergency_withdraw_delay;
+    ctx.accounts.vault_config.recovery_address = recovery_address;
+
+    emit!(EmergencyWithdrawConfigChanged {
+        emergency_withdraw_delay,
+        recovery_address,
     });
 
     Ok(())
@@ -0,0 +1,56 @@
+use anchor_lang::prelude::*;
+use anchor_lang::Discriminator;
+use anchor_spl::token::Mint;
+
+use crate::{ constants::POSITION_SEED, errors::VaultError, state::UserPosition };
+
+// Grows a position created with an older layout to the current size, callable by anyone.
+// New fields start zeroed, so nothing is pending or enabled until set.
+#[derive(Accounts)]
+pub struct MigratePosition<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// CHECK: deserialized by hand, an older layout is too short for Account<UserPosition>
+    #[account(mut, seeds = [POSITION_SEED, position_nft.key().as_ref()], bump, owner = crate::ID)]
+    pub user_position: AccountInfo<'info>,
+
+    // Position token mint
+    pub position_nft: Account<'info, Mint>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn process_migrate_position(ctx: Context<MigratePosition>) -> Result<()> {
+    let user_position = &ctx.accounts.user_position;
+    {
+        let data = user_position.try_borrow_data()?;
+        require!(
+            data.len() >= 8 && data[..8] == UserPosition::DISCRIMINATOR,
+            VaultError::InvalidPosition
+        );
+    }
+
+    let new_len = 8 + std::mem::size_of::<UserPosition>();
+    if user_position.data_len() >= new_len {
+        return Ok(());
+    }
+
+    let lamports_needed = Rent::get()?.minimum_balance(new_len).saturating_sub(user_position.lamports());
+    if lamports_needed > 0 {
+        anchor_lang::system_program::transfer(
+            CpiContext::new(
+                ctx.accounts.system_program.to_account_info(),
+                anchor_lang::system_program::Transfer {
+                    from: ctx.accounts.payer.to_account_info(),
+                    to: user_position.to_account_info(),
+                },
+            ),
+            lamports_needed
+        )?;
+    }
+
+    user_position.realloc(new_len, true)?;
+
+    Ok(())
+}
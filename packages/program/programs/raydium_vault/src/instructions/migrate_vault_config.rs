@@ -0,0 +1,79 @@
+use anchor_lang::prelude::*;
+use anchor_lang::Discriminator;
+
+use crate::{
+    constants::{ DEFAULT_EMERGENCY_WITHDRAW_DELAY, MIN_EMERGENCY_WITHDRAW_DELAY, VAULT_CONFIG_SEED },
+    errors::VaultError,
+    state::VaultConfig,
+};
+
+// Grows a vault config created with an older layout to the current size.
+// New fields start zeroed, so nothing is pending or enabled until set, except the emergency
+// withdraw delay, which is raised to the default when it is below the minimum.
+#[derive(Accounts)]
+pub struct MigrateVaultConfig<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    /// CHECK: deserialized by hand, an older layout is too short for Account<VaultConfig>
+    #[account(mut, seeds = [VAULT_CONFIG_SEED], bump, owner = crate::ID)]
+    pub vault_config: AccountInfo<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+// Offset of manager_authority, which every layout shares
+const MANAGER_AUTHORITY_OFFSET: usize = 8 + 32 + 32;
+// Offset of emergency_withdraw_delay, after the pending authorities and the protocol fee
+const EMERGENCY_WITHDRAW_DELAY_OFFSET: usize = MANAGER_AUTHORITY_OFFSET + 32 * 4 + 2 + 32;
+
+pub fn process_migrate_vault_config(ctx: Context<MigrateVaultConfig>) -> Result<()> {
+    let vault_config = &ctx.accounts.vault_config;
+    {
+        let data = vault_config.try_borrow_data()?;
+        require!(
+            data.len() >= MANAGER_AUTHORITY_OFFSET + 32 && data[..8] == VaultConfig::DISCRIMINATOR,
+            VaultError::InvalidVaultConfig
+        );
+        let manager = Pubkey::try_from(&data[MANAGER_AUTHORITY_OFFSET..MANAGER_AUTHORITY_OFFSET + 32])
+            .map_err(|_| VaultError::InvalidVaultConfig)?;
+        require!(manager == ctx.accounts.authority.key(), VaultError::Unauthorized);
+    }
+
+    let new_len = 8 + std::mem::size_of::<VaultConfig>();
+    if vault_config.data_len() < new_len {
+        grow(&ctx, new_len)?;
+    }
+
+    let mut data = vault_config.try_borrow_mut_data()?;
+    let delay = &mut data[EMERGENCY_WITHDRAW_DELAY_OFFSET..EMERGENCY_WITHDRAW_DELAY_OFFSET + 8];
+    let current = i64::from_le_bytes(
+        (&*delay).try_into().map_err(|_| VaultError::InvalidVaultConfig)?
+    );
+    if current < MIN_EMERGENCY_WITHDRAW_DELAY {
+        delay.copy_from_slice(&DEFAULT_EMERGENCY_WITHDRAW_DELAY.to_le_bytes());
+    }
+
+    Ok(())
+}
+
+fn grow(ctx: &Context<MigrateVaultConfig>, new_len: usize) -> Result<()> {
+    let vault_config = &ctx.accounts.vault_config;
+    let lamports_needed = Rent::get()?.minimum_balance(new_len).saturating_sub(vault_config.lamports());
+    if lamports_needed > 0 {
+        anchor_lang::system_program::transfer(
+            CpiContext::new(
+                ctx.accounts.system_program.to_account_info(),
+                anchor_lang::system_program::Transfer {
+                    from: ctx.accounts.authority.to_account_info(),
+                    to: vault_config.to_account_info(),
+                },
+            ),
+            lamports_needed
+        )?;
+    }
+
+    vault_config.realloc(new_len, true)?;
+
+    Ok(())
+}
@@ -10,8 +10,32 @@ pub use manage_authority::*;
 pub mod claim;
 pub use claim::*;
 
+pub mod claim_many;
+pub use claim_many::*;
+
+pub mod claim_as_sol;
+pub use claim_as_sol::*;
+
 pub mod emergency_withdraw;
 pub use emergency_withdraw::*;
 
 pub mod change_claimer;
 pub use change_claimer::*;
+
+pub mod migrate_vault_config;
+pub use migrate_vault_config::*;
+
+pub mod set_permissionless_claim;
+pub use set_permissionless_claim::*;
+
+pub mod migrate_position;
+pub use migrate_position::*;
+
+pub mod set_fee_shares;
+pub use set_fee_shares::*;
+
+pub mod set_compound;
+pub use set_compound::*;
+
+pub mod compound;
+pub use compound::*;
@@ -0,0 +1,40 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::Mint;
+
+use crate::{
+    constants::POSITION_SEED,
+    errors::VaultError,
+    events::CompoundChanged,
+    state::UserPosition,
+    utils::get_current_timestamp,
+};
+
+// Claimer opts their position into compounding its fees back into the pool, or back out
+#[derive(Accounts)]
+pub struct SetCompound<'info> {
+    #[account(constraint = claimer.key() == user_position.claimer.key() @VaultError::Unauthorized)]
+    pub claimer: Signer<'info>,
+
+    #[account(mut, seeds = [POSITION_SEED, position_nft.key().as_ref()], bump)]
+    pub user_position: Account<'info, UserPosition>,
+
+    // Position token mint
+    pub position_nft: Account<'info, Mint>,
+}
+
+pub fn process_set_compound(
+    ctx: Context<SetCompound>,
+    enabled: bool
+) -> Result<()> {
+    let position = &mut ctx.accounts.user_position;
+    position.compound = enabled;
+    position.last_updated = get_current_timestamp()?;
+
+    emit!(CompoundChanged {
+        claimer: position.claimer,
+        position_nft: position.position_nft,
+        enabled,
+    });
+
+    Ok(())
+}
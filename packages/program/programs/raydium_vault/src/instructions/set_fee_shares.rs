@@ -0,0 +1,56 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::Mint;
+
+use crate::{
+    constants::{ BPS_DENOMINATOR, MAX_FEE_SHARES, POSITION_SEED, VAULT_CONFIG_SEED },
+    errors::VaultError,
+    events::FeeSharesChanged,
+    state::{ FeeShare, UserPosition, VaultConfig },
+    utils::get_current_timestamp,
+};
+
+// Set how a position's claimed fees are split, executor only.
+// An empty list sends all fees to the claimer again.
+#[derive(Accounts)]
+pub struct SetFeeShares<'info> {
+    #[account(mut, constraint = authority.key() == vault_config.executor_authority.key() @VaultError::Unauthorized)]
+    pub authority: Signer<'info>,
+
+    #[account(seeds = [VAULT_CONFIG_SEED], bump)]
+    pub vault_config: Account<'info, VaultConfig>,
+
+    #[account(mut, seeds = [POSITION_SEED, position_nft.key().as_ref()], bump)]
+    pub user_position: Account<'info, UserPosition>,
+
+    // Position token mint
+    pub position_nft: Account<'info, Mint>,
+}
+
+pub fn process_set_fee_shares(ctx: Context<SetFeeShares>, fee_shares: Vec<FeeShare>) -> Result<()> {
+    require!(fee_shares.len() <= MAX_FEE_SHARES, VaultError::TooManyFeeShares);
+
+    if !fee_shares.is_empty() {
+        require!(
+            fee_shares.iter().all(|share| share.bps > 0),
+            VaultError::InvalidFeeShares
+        );
+        let total_bps: u64 = fee_shares
+            .iter()
+            .map(|share| share.bps as u64)
+            .sum();
+        require!(total_bps == BPS_DENOMINATOR, VaultError::InvalidFeeShares);
+    }
+
+    let position = &mut ctx.accounts.user_position;
+    position.fee_shares = [FeeShare::default(); MAX_FEE_SHARES];
+    position.fee_shares[..fee_shares.len()].copy_from_slice(&fee_shares);
+    position.fee_share_count = fee_shares.len() as u8;
+    position.last_updated = get_current_timestamp()?;
+
+    emit!(FeeSharesChanged {
+        position_nft: position.position_nft,
+        fee_shares,
+    });
+
+    Ok(())
+}
@@ -44,6 +44,7 @@ pub fn process_initialize(
         init_config.emergency_authority,
         init_config.manager_authority,
     );
+    vault_config.emergency_withdraw_delay = DEFAULT_EMERGENCY_WITHDRAW_DELAY;
 
     emit!(VaultInitialized {
         executor: vault_config.executor_authority,
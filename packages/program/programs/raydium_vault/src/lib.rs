@@ -8,6 +8,7 @@ pub mod utils;
 pub mod events;
 
 use instructions::*;
+use state::FeeShare;
 
 declare_id!("autoFENwXX1Y3V4pkUdJw7WzhF1ZT6xQsyJWkLqBcta");
 
@@ -31,37 +32,150 @@ pub mod raydium_vault {
         Ok(())
     }
 
-    pub fn change_executor_authority(
+    pub fn nominate_executor_authority(
         ctx: Context<UpdateExecutor>,
         new_executor: Pubkey
     ) -> Result<()> {
-        manage_authority::change_executor_authority(ctx, new_executor)?;
+        manage_authority::nominate_executor_authority(ctx, new_executor)?;
         Ok(())
     }
 
-    pub fn change_manager_authority(
+    pub fn accept_executor_authority(ctx: Context<AcceptExecutor>) -> Result<()> {
+        manage_authority::accept_executor_authority(ctx)?;
+        Ok(())
+    }
+
+    pub fn cancel_executor_nomination(ctx: Context<UpdateExecutor>) -> Result<()> {
+        manage_authority::cancel_executor_nomination(ctx)?;
+        Ok(())
+    }
+
+    pub fn nominate_manager_authority(
         ctx: Context<UpdateManager>,
         new_manager: Pubkey
     ) -> Result<()> {
-        manage_authority::change_manager_authority(ctx, new_manager)?;
+        manage_authority::nominate_manager_authority(ctx, new_manager)?;
         Ok(())
     }
 
-    pub fn change_emergency_authority(
+    pub fn accept_manager_authority(ctx: Context<AcceptManager>) -> Result<()> {
+        manage_authority::accept_manager_authority(ctx)?;
+        Ok(())
+    }
+
+    pub fn cancel_manager_nomination(ctx: Context<UpdateManager>) -> Result<()> {
+        manage_authority::cancel_manager_nomination(ctx)?;
+        Ok(())
+    }
+
+    pub fn nominate_emergency_authority(
         ctx: Context<UpdateEmergency>,
         new_emergency: Pubkey
     ) -> Result<()> {
-        manage_authority::change_emergency_authority(ctx, new_emergency)?;
+        manage_authority::nominate_emergency_authority(ctx, new_emergency)?;
         Ok(())
     }
 
-    pub fn change_claimer(ctx: Context<ChangeClaimer>, new_claimer: Pubkey) -> Result<()> {
-        change_claimer::process_change_claimer(ctx, new_claimer)?;
+    pub fn accept_emergency_authority(ctx: Context<AcceptEmergency>) -> Result<()> {
+        manage_authority::accept_emergency_authority(ctx)?;
         Ok(())
     }
 
-    pub fn emergency_withdraw(ctx: Context<EmergencyWithdraw>) -> Result<()> {
-        emergency_withdraw::process_emergency_withdraw(ctx)?;
+    pub fn cancel_emergency_nomination(ctx: Context<UpdateEmergency>) -> Result<()> {
+        manage_authority::cancel_emergency_nomination(ctx)?;
+        Ok(())
+    }
+
+    pub fn set_protocol_fee(
+        ctx: Context<UpdateManager>,
+        protocol_fee_bps: u16,
+        protocol_fee_recipient: Pubkey
+    ) -> Result<()> {
+        manage_authority::set_protocol_fee(ctx, protocol_fee_bps, protocol_fee_recipient)?;
+        Ok(())
+    }
+
+    pub fn set_emergency_withdraw_config(
+        ctx: Context<UpdateManager>,
+        emergency_withdraw_delay: i64,
+        recovery_address: Pubkey
+    ) -> Result<()> {
+        manage_authority::set_emergency_withdraw_config(
+            ctx,
+            emergency_withdraw_delay,
+            recovery_address
+        )?;
+        Ok(())
+    }
+
+    pub fn migrate_vault_config(ctx: Context<MigrateVaultConfig>) -> Result<()> {
+        migrate_vault_config::process_migrate_vault_config(ctx)?;
+        Ok(())
+    }
+
+    // Executor override, reason is a support reason code carried by the event
+    pub fn change_claimer(
+        ctx: Context<ChangeClaimer>,
+        new_claimer: Pubkey,
+        reason: u16
+    ) -> Result<()> {
+        change_claimer::process_change_claimer(ctx, new_claimer, reason)?;
+        Ok(())
+    }
+
+    pub fn nominate_claimer(ctx: Context<NominateClaimer>, new_claimer: Pubkey) -> Result<()> {
+        change_claimer::process_nominate_claimer(ctx, new_claimer)?;
+        Ok(())
+    }
+
+    pub fn accept_claimer(ctx: Context<AcceptClaimer>) -> Result<()> {
+        change_claimer::process_accept_claimer(ctx)?;
+        Ok(())
+    }
+
+    pub fn migrate_position(ctx: Context<MigratePosition>) -> Result<()> {
+        migrate_position::process_migrate_position(ctx)?;
+        Ok(())
+    }
+
+    pub fn set_permissionless_claim(
+        ctx: Context<SetPermissionlessClaim>,
+        enabled: bool
+    ) -> Result<()> {
+        set_permissionless_claim::process_set_permissionless_claim(ctx, enabled)?;
+        Ok(())
+    }
+
+    pub fn set_compound(ctx: Context<SetCompound>, enabled: bool) -> Result<()> {
+        set_compound::process_set_compound(ctx, enabled)?;
+        Ok(())
+    }
+
+    pub fn set_fee_shares(ctx: Context<SetFeeShares>, fee_shares: Vec<FeeShare>) -> Result<()> {
+        set_fee_shares::process_set_fee_shares(ctx, fee_shares)?;
+        Ok(())
+    }
+
+    pub fn request_emergency_withdraw(
+        ctx: Context<RequestEmergencyWithdraw>,
+        reason: u16
+    ) -> Result<()> {
+        emergency_withdraw::process_request_emergency_withdraw(ctx, reason)?;
+        Ok(())
+    }
+
+    pub fn veto_emergency_withdraw(ctx: Context<VetoEmergencyWithdraw>) -> Result<()> {
+        emergency_withdraw::process_veto_emergency_withdraw(ctx)?;
+        Ok(())
+    }
+
+    pub fn cancel_emergency_withdraw(ctx: Context<CancelEmergencyWithdraw>) -> Result<()> {
+        emergency_withdraw::process_cancel_emergency_withdraw(ctx)?;
+        Ok(())
+    }
+
+    pub fn execute_emergency_withdraw(ctx: Context<ExecuteEmergencyWithdraw>) -> Result<()> {
+        emergency_withdraw::process_execute_emergency_withdraw(ctx)?;
         Ok(())
     }
 
@@ -72,4 +186,32 @@ pub mod raydium_vault {
         claim::process_claim(ctx)?;
         Ok(())
     }
+
+    #[inline(never)]
+    pub fn claim_many<'a, 'b, 'c: 'info, 'info>(
+        ctx: Context<'a, 'b, 'c, 'info, ClaimMany<'info>>
+    ) -> Result<()> {
+        claim_many::process_claim_many(ctx)?;
+        Ok(())
+    }
+
+    pub fn claim_as_sol(ctx: Context<ClaimAsSol>, minimum_amount_out: u64) -> Result<()> {
+        claim_as_sol::process_claim_as_sol(ctx, minimum_amount_out)?;
+        Ok(())
+    }
+
+    pub fn compound(
+        ctx: Context<Compound>,
+        lp_token_amount: u64,
+        maximum_token_0_amount: u64,
+        maximum_token_1_amount: u64
+    ) -> Result<()> {
+        compound::process_compound(
+            ctx,
+            lp_token_amount,
+            maximum_token_0_amount,
+            maximum_token_1_amount
+        )?;
+        Ok(())
+    }
 }
@@ -1,5 +1,7 @@
 use anchor_lang::prelude::*;
 
+use crate::constants::MAX_FEE_SHARES;
+
 #[account]
 pub struct VaultConfig {
     /// The authority that can manage executing operations
@@ -10,6 +12,35 @@ pub struct VaultConfig {
 
     /// The authority that can manage administrative operations
     pub manager_authority: Pubkey,
+
+    /// Nominated executor, takes over once it accepts. Pubkey::default() when none
+    pub pending_executor_authority: Pubkey,
+
+    /// Nominated emergency authority, takes over once it accepts. Pubkey::default() when none
+    pub pending_emergency_authority: Pubkey,
+
+    /// Nominated manager, takes over once it accepts. Pubkey::default() when none
+    pub pending_manager_authority: Pubkey,
+
+    /// Share of claimed fees withheld for the protocol, in basis points
+    pub protocol_fee_bps: u16,
+
+    /// Owner of the token accounts the protocol fee is paid to
+    pub protocol_fee_recipient: Pubkey,
+
+    /// Seconds between an emergency withdraw request and its execution, the claimer's veto window
+    pub emergency_withdraw_delay: i64,
+
+    /// Manager-approved owner emergency withdraws may go to besides the claimer. Pubkey::default() when none
+    pub recovery_address: Pubkey,
+}
+
+/// Roles held in VaultConfig, used by the nomination events
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum AuthorityRole {
+    Executor,
+    Emergency,
+    Manager,
 }
 
 #[account]
@@ -28,4 +59,63 @@ pub struct UserPosition {
 
     /// Timestamp of the last update to the position
     pub last_updated: i64,
+
+    /// Set by the claimer to let anyone crank claim for this position
+    pub permissionless_claim: bool,
+
+    /// Claimer nominated by the current claimer, takes over once it accepts. Pubkey::default() when none
+    pub pending_claimer: Pubkey,
+
+    /// Number of fee_shares in use. With none, all fees go to the claimer
+    pub fee_share_count: u8,
+
+    /// Recipients of the claimed fees, the first fee_share_count entries add up to BPS_DENOMINATOR
+    pub fee_shares: [FeeShare; MAX_FEE_SHARES],
+
+    /// Lifetime token_0 fees collected for this position, before the protocol fee and fee shares
+    pub total_claimed_0: u64,
+
+    /// Lifetime token_1 fees collected for this position, before the protocol fee and fee shares
+    pub total_claimed_1: u64,
+
+    /// Set by the claimer to have fees compounded back into the pool instead of paid out
+    pub compound: bool,
+
+    /// Timestamp of the claimer's last emergency withdraw veto, 0 when none
+    pub last_vetoed_at: i64,
+}
+
+impl UserPosition {
+    pub fn fee_shares(&self) -> &[FeeShare] {
+        &self.fee_shares[..self.fee_share_count as usize]
+    }
+}
+
+/// Share of a position's fees paid to a recipient
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Default, Debug)]
+pub struct FeeShare {
+    pub recipient: Pubkey,
+    pub bps: u16,
+}
+
+/// Pending emergency withdraw of a position nft, executable once the delay has passed
+#[account]
+pub struct EmergencyWithdrawRequest {
+    /// The position nft to withdraw
+    pub position_nft: Pubkey,
+
+    /// Token account the nft is sent to
+    pub destination: Pubkey,
+
+    /// Reason code recorded by the emergency authority
+    pub reason: u16,
+
+    /// Emergency authority that made the request, refunded the rent when it closes
+    pub requester: Pubkey,
+
+    /// Timestamp of the request
+    pub requested_at: i64,
+
+    /// Timestamp from which the request can be executed
+    pub executable_at: i64,
 }
@@ -1,7 +1,37 @@
 use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{ self, Mint, TransferChecked };
 
 /// Get current timestamp
 pub fn get_current_timestamp() -> Result<i64> {
     let clock = Clock::get()?;
     Ok(clock.unix_timestamp)
 }
+
+/// Transfer tokens out of an account owned by the vault config, with the program of the mint
+pub fn transfer_from_vault<'info>(
+    token_program: AccountInfo<'info>,
+    from: AccountInfo<'info>,
+    mint: &InterfaceAccount<'info, Mint>,
+    to: AccountInfo<'info>,
+    vault_config: AccountInfo<'info>,
+    signer_seeds: &[&[&[u8]]],
+    amount: u64
+) -> Result<()> {
+    if amount == 0 {
+        return Ok(());
+    }
+    token_interface::transfer_checked(
+        CpiContext::new_with_signer(
+            token_program,
+            TransferChecked {
+                from,
+                mint: mint.to_account_info(),
+                to,
+                authority: vault_config,
+            },
+            signer_seeds
+        ),
+        amount,
+        mint.decimals
+    )
+}
@@ -0,0 +1,64 @@
+import * as anchor from '@coral-xyz/anchor';
+import { Program, web3 } from '@coral-xyz/anchor';
+import type { RaydiumVault } from '../target/types/raydium_vault';
+import { vaultConfigSeed } from "./utils.ts";
+
+// Accepts a raydium_vault role nominated to the provider wallet.
+// ROLE=executor|emergency|manager, defaults to executor
+(async () => {
+  const role = (process.env.ROLE ?? "executor").toLowerCase();
+  console.log(`Starting accept ${role} authority`);
+
+  // This MUST be configured with the keypair of the nominated authority.
+  const provider = anchor.AnchorProvider.env();
+  anchor.setProvider(provider);
+
+  const nomineeWallet = provider.wallet;
+  console.log(`Attempting to accept ${role} authority as: ${nomineeWallet.publicKey.toBase58()}`);
+
+  const raydiumProgram = anchor.workspace.RaydiumVault as Program<RaydiumVault>;
+
+  const [vaultConfigPDA] = web3.PublicKey.findProgramAddressSync(
+    [Buffer.from(vaultConfigSeed)],
+    raydiumProgram.programId
+  );
+
+  try {
+    const vaultConfig = await raydiumProgram.account.vaultConfig.fetch(vaultConfigPDA);
+    const pending = {
+      executor: vaultConfig.pendingExecutorAuthority,
+      emergency: vaultConfig.pendingEmergencyAuthority,
+      manager: vaultConfig.pendingManagerAuthority,
+    }[role];
+
+    if (!pending) {
+      console.error(`Error: unknown role ${role}`);
+      return;
+    }
+    if (!pending.equals(nomineeWallet.publicKey)) {
+      console.error(`Error: the configured wallet is not the pending ${role} authority (${pending.toBase58()}).`);
+      return;
+    }
+
+    const accounts = {
+      newAuthority: nomineeWallet.publicKey,
+      vaultConfig: vaultConfigPDA,
+    };
+    const method = {
+      executor: () => raydiumProgram.methods.acceptExecutorAuthority(),
+      emergency: () => raydiumProgram.methods.acceptEmergencyAuthority(),
+      manager: () => raydiumProgram.methods.acceptManagerAuthority(),
+    }[role];
+
+    const txSignature = await method().accounts(accounts).rpc();
+
+    console.log("Transaction sent successfully!");
+    console.log("Signature:", txSignature);
+  } catch (err) {
+    console.error(`Accept ${role} authority transaction failed:`, err);
+    if (err instanceof anchor.AnchorError) {
+        console.error("AnchorError Details:", err.error);
+        console.error("Error Logs:", err.logs);
+    }
+  }
+})();
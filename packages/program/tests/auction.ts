@@ -0,0 +1,228 @@
+import * as anchor from "@coral-xyz/anchor";
+import * as spl from "@solana/spl-token";
+import { Program, BN } from "@coral-xyz/anchor";
+import { assert } from "chai";
+import { Autofun } from "../target/types/autofun";
+import NodeWallet from "@coral-xyz/anchor/dist/cjs/nodewallet";
+import { connection, createAta, fundWallet, launchCurve } from "./utils";
+
+const sleepUntil = async (timestamp: number) => {
+    while (Math.floor(Date.now() / 1000) <= timestamp) {
+        await new Promise((resolve) => setTimeout(resolve, 1000));
+    }
+};
+
+describe("autofun", () => {
+    const provider = anchor.AnchorProvider.env();
+    anchor.setProvider(provider);
+
+    const nodeWallet = provider.wallet as NodeWallet;
+    const signerWallet = anchor.web3.Keypair.fromSecretKey(
+        nodeWallet.payer.secretKey
+    );
+
+    const program = anchor.workspace.Autofun as Program<Autofun>;
+
+    const alice = anchor.web3.Keypair.generate();
+    const bob = anchor.web3.Keypair.generate();
+
+    const aliceBid = new BN(anchor.web3.LAMPORTS_PER_SOL);
+    const bobBid = new BN(3 * anchor.web3.LAMPORTS_PER_SOL);
+
+    /// Launches a scheduled curve and opens an auction ending at endsAt
+    const openAuction = async (endsAt: number, tradingStartsAt: number, expiresAt?: number) => {
+        const curve = await launchCurve(program, signerWallet, {
+            tradingStartsAt: new BN(tradingStartsAt),
+            expiresAt: expiresAt ? new BN(expiresAt) : undefined,
+        });
+        const [auction] = anchor.web3.PublicKey.findProgramAddressSync(
+            [Buffer.from("auction"), curve.bondingCurve.toBuffer()],
+            program.programId
+        );
+        await program.methods
+            .openAuction(new BN(endsAt))
+            .accountsPartial({
+                creator: signerWallet.publicKey,
+                tokenMint: curve.tokenMint,
+                bondingCurve: curve.bondingCurve,
+                auction,
+            })
+            .signers([signerWallet])
+            .rpc({ commitment: "confirmed" });
+        return { ...curve, auction };
+    };
+
+    const placeBid = (
+        auction: anchor.web3.PublicKey,
+        bidder: anchor.web3.Keypair,
+        amount: BN
+    ) =>
+        program.methods
+            .placeBid(amount)
+            .accountsPartial({ auction, bidder: bidder.publicKey })
+            .signers([bidder])
+            .rpc({ commitment: "confirmed" });
+
+    const bidAccount = (
+        curve: Awaited<ReturnType<typeof openAuction>>,
+        bidder: anchor.web3.Keypair
+    ) =>
+        anchor.web3.PublicKey.findProgramAddressSync(
+            [Buffer.from("bid"), curve.auction.toBuffer(), bidder.publicKey.toBuffer()],
+            program.programId
+        )[0];
+
+    const claimAuction = async (
+        curve: Awaited<ReturnType<typeof openAuction>>,
+        bidder: anchor.web3.Keypair
+    ) => {
+        const bid = bidAccount(curve, bidder);
+        return program.methods
+            .claimAuction()
+            .accountsPartial({
+                tokenMint: curve.tokenMint,
+                bondingCurve: curve.bondingCurve,
+                auction: curve.auction,
+                bid,
+                globalVault: curve.globalVault,
+                globalAta: curve.globalAta,
+                bidderTokenAccount: spl.getAssociatedTokenAddressSync(
+                    curve.tokenMint,
+                    bidder.publicKey
+                ),
+                bidder: bidder.publicKey,
+            })
+            .signers([bidder])
+            .rpc({ commitment: "confirmed" });
+    };
+
+    const traderRecord = (
+        curve: Awaited<ReturnType<typeof openAuction>>,
+        bidder: anchor.web3.Keypair
+    ) =>
+        anchor.web3.PublicKey.findProgramAddressSync(
+            [Buffer.from("trader_record"), curve.bondingCurve.toBuffer(), bidder.publicKey.toBuffer()],
+            program.programId
+        )[0];
+
+    before(async () => {
+        for (const bidder of [alice, bob]) {
+            await fundWallet(signerWallet, bidder.publicKey, 10 * anchor.web3.LAMPORTS_PER_SOL);
+        }
+    });
+
+    it("Settled auction pays each bidder pro-rata at one price", async () => {
+        const now = Math.floor(Date.now() / 1000);
+        const curve = await openAuction(now + 10, now + 20);
+        for (const bidder of [alice, bob]) {
+            await createAta(signerWallet, curve.tokenMint, bidder.publicKey);
+        }
+
+        await placeBid(curve.auction, alice, aliceBid);
+        await placeBid(curve.auction, bob, bobBid);
+
+        try {
+            await program.methods
+                .settleAuction()
+                .accountsPartial({
+                    tokenMint: curve.tokenMint,
+                    bondingCurve: curve.bondingCurve,
+                    auction: curve.auction,
+                    feeVault: curve.feeVault,
+                    globalVault: curve.globalVault,
+                })
+                .rpc({ commitment: "confirmed" });
+            assert.fail("settled during the commit window");
+        } catch (err) {
+            assert.equal(err.error?.errorCode?.code, "AuctionNotEnded");
+        }
+
+        await sleepUntil(now + 10);
+        await program.methods
+            .settleAuction()
+            .accountsPartial({
+                tokenMint: curve.tokenMint,
+                bondingCurve: curve.bondingCurve,
+                auction: curve.auction,
+                feeVault: curve.feeVault,
+                globalVault: curve.globalVault,
+            })
+            .rpc({ commitment: "confirmed" });
+
+        const auction = await program.account.auction.fetch(curve.auction);
+        assert.isTrue(auction.isSettled);
+        assert.equal(auction.totalBids.toString(), aliceBid.add(bobBid).toString());
+        assert.isAbove(auction.tokensOut.toNumber(), 0);
+
+        const curveAccount = await program.account.bondingCurve.fetch(curve.bondingCurve);
+        assert.equal(curveAccount.auctionEndsAt.toNumber(), 0);
+
+        const unspent = auction.totalBids.sub(auction.lamportsUsed);
+        for (const [bidder, amount] of [[alice, aliceBid], [bob, bobBid]] as const) {
+            const lamportsBefore = await connection.getBalance(bidder.publicKey);
+            const bidRent = await connection.getBalance(bidAccount(curve, bidder));
+            await claimAuction(curve, bidder);
+            assert.isNull(await connection.getAccountInfo(bidAccount(curve, bidder)));
+
+            const tokenAccount = await spl.getAccount(
+                connection,
+                spl.getAssociatedTokenAddressSync(curve.tokenMint, bidder.publicKey)
+            );
+            assert.equal(
+                tokenAccount.amount.toString(),
+                amount.mul(auction.tokensOut).div(auction.totalBids).toString()
+            );
+
+            // The won tokens back the bidder's refunds if the curve later expires
+            const record = await program.account.traderRecord.fetch(traderRecord(curve, bidder));
+            assert.equal(record.boughtToken.toString(), tokenAccount.amount.toString());
+
+            // The bidder gets the bid's rent back, and pays the transaction fee and the trader record's rent
+            const rent = await connection.getBalance(traderRecord(curve, bidder));
+            assert.equal(
+                (await connection.getBalance(bidder.publicKey)) - lamportsBefore,
+                amount.mul(unspent).div(auction.totalBids).toNumber() + bidRent - 5000 - rent
+            );
+        }
+
+        try {
+            await claimAuction(curve, alice);
+            assert.fail("claimed a bid twice");
+        } catch (err) {
+            // The bid is closed by the first claim
+            assert.equal(err.error?.errorCode?.code, "AccountNotInitialized");
+        }
+    });
+
+    it("Unsettled auction of an expired curve refunds every bid", async () => {
+        const now = Math.floor(Date.now() / 1000);
+        const curve = await openAuction(now + 10, now + 15, now + 20);
+        await createAta(signerWallet, curve.tokenMint, alice.publicKey);
+
+        await placeBid(curve.auction, alice, aliceBid);
+
+        try {
+            await claimAuction(curve, alice);
+            assert.fail("claimed an unsettled bid");
+        } catch (err) {
+            assert.equal(err.error?.errorCode?.code, "AuctionNotSettled");
+        }
+
+        await sleepUntil(now + 20);
+        const lamportsBefore = await connection.getBalance(alice.publicKey);
+        const bidRent = await connection.getBalance(bidAccount(curve, alice));
+        await claimAuction(curve, alice);
+        assert.isNull(await connection.getAccountInfo(bidAccount(curve, alice)));
+
+        const rent = await connection.getBalance(traderRecord(curve, alice));
+        assert.equal(
+            (await connection.getBalance(alice.publicKey)) - lamportsBefore,
+            aliceBid.toNumber() + bidRent - 5000 - rent
+        );
+        const tokenAccount = await spl.getAccount(
+            connection,
+            spl.getAssociatedTokenAddressSync(curve.tokenMint, alice.publicKey)
+        );
+        assert.equal(tokenAccount.amount.toString(), "0");
+    });
+});
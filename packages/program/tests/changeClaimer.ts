@@ -1,5 +1,6 @@
 import * as anchor from "@coral-xyz/anchor";
 import { Program } from "@coral-xyz/anchor";
+import { assert } from "chai";
 import { RaydiumVault } from "../target/types/raydium_vault";
 import NodeWallet from "@coral-xyz/anchor/dist/cjs/nodewallet";
 import {
@@ -23,20 +24,76 @@ describe("raydium_vault", () => {
 
     const program = anchor.workspace.RaydiumVault as Program<RaydiumVault>;
 
-    it("Change Claimer", async function () {
-        const isDev = isDevnet(connection);
-        const position_nft = getNftAddress(isDev);
-        const [vault_config] = anchor.web3.PublicKey.findProgramAddressSync(
-            [Buffer.from(vaultConfigSeed)],
-            program.programId
-        );
-        const [user_position] = anchor.web3.PublicKey.findProgramAddressSync(
-            [Buffer.from(positionSeed), position_nft.toBuffer()],
-            program.programId
+    const isDev = isDevnet(connection);
+    const position_nft = getNftAddress(isDev);
+    const [vault_config] = anchor.web3.PublicKey.findProgramAddressSync(
+        [Buffer.from(vaultConfigSeed)],
+        program.programId
+    );
+    const [user_position] = anchor.web3.PublicKey.findProgramAddressSync(
+        [Buffer.from(positionSeed), position_nft.toBuffer()],
+        program.programId
+    );
+
+    const setFeeShares = () =>
+        program.rpc.setFeeShares(
+            [{ recipient: signerWallet.publicKey, bps: 10000 }],
+            {
+                accounts: {
+                    authority: signerWallet.publicKey,
+                    vaultConfig: vault_config,
+                    userPosition: user_position,
+                    positionNft: position_nft,
+                },
+            }
         );
+
+    it("Claimer handover keeps the fee shares", async function () {
+        // Hand the position to the signer wallet so it can nominate
+        await program.rpc.changeClaimer(signerWallet.publicKey, 0, {
+            accounts: {
+                authority: signerWallet.publicKey,
+                vaultConfig: vault_config,
+                userPosition: user_position,
+                positionNft: position_nft,
+            },
+        });
+        await setFeeShares();
+
+        const newClaimer = anchor.web3.Keypair.generate();
+        await program.methods
+            .nominateClaimer(newClaimer.publicKey)
+            .accountsPartial({
+                claimer: signerWallet.publicKey,
+                userPosition: user_position,
+                positionNft: position_nft,
+            })
+            .rpc();
+        await program.methods
+            .acceptClaimer()
+            .accountsPartial({
+                newClaimer: newClaimer.publicKey,
+                userPosition: user_position,
+                positionNft: position_nft,
+            })
+            .signers([newClaimer])
+            .rpc();
+
+        // Only the executor changes fee shares, a claimer handing over to another wallet can't drop them
+        const position = await program.account.userPosition.fetch(user_position);
+        assert.ok(position.claimer.equals(newClaimer.publicKey));
+        assert.equal(position.feeShareCount, 1);
+        assert.ok(position.feeShares[0].recipient.equals(signerWallet.publicKey));
+    });
+
+    it("Change Claimer", async function () {
         const claimer_address = claimer_address_0;
 
-        await program.rpc.changeClaimer(claimer_address, {
+        // Fee shares set up for the old claimer must not carry over to the new one
+        await setFeeShares();
+
+        const reason = 1; // support reason code, carried by the ClaimerOverridden event
+        await program.rpc.changeClaimer(claimer_address, reason, {
             accounts: {
                 authority: signerWallet.publicKey,
                 vaultConfig: vault_config,
@@ -44,5 +101,9 @@ describe("raydium_vault", () => {
                 positionNft: position_nft,
             },
         });
+
+        const position = await program.account.userPosition.fetch(user_position);
+        assert.ok(position.claimer.equals(claimer_address));
+        assert.equal(position.feeShareCount, 0);
     });
 });
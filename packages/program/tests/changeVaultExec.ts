@@ -19,9 +19,9 @@ import type { RaydiumVault } from '../target/types/raydium_vault';
   // Define the new executor authority public key
   const newExecutorAuthority = new anchor.web3.PublicKey('autozgbVb1EvhrTZTkpLekJRN4sN5hhGYpMMiY9kQ5S'); // Replace with the actual new executor public key
 
-  // Call the change_executor_authority function
+  // Nominate the new executor, it takes over once it signs accept_executor_authority
   try {
-  const txSignature = await raydiumProgram.methods.changeExecutorAuthority(newExecutorAuthority).accounts({
+  const txSignature = await raydiumProgram.methods.nominateExecutorAuthority(newExecutorAuthority).accounts({
     authority: provider.wallet.publicKey, // Current manager authority
     vaultConfig: vaultConfigPDA, // Ensure camelCase is used here too
   }).rpc();
@@ -29,6 +29,6 @@ import type { RaydiumVault } from '../target/types/raydium_vault';
   console.log("Transaction sent successfully!");
   console.log("Signature:", txSignature);
   } catch (error) {
-    console.error("Error changing executor authority:", error);
+    console.error("Error nominating executor authority:", error);
   }
 })();
\ No newline at end of file
@@ -21,14 +21,14 @@ import { vaultConfigSeed } from "./utils.ts"; // Removed .ts extension
   // IMPORTANT: Replace with the ACTUAL desired new manager public key
   const newManagerAuthority = new anchor.web3.PublicKey('autozgbVb1EvhrTZTkpLekJRN4sN5hhGYpMMiY9kQ5S');
 
-  console.log(`Attempting to change manager for vault: ${vaultConfigPDA.toString()}`);
+  console.log(`Attempting to nominate manager for vault: ${vaultConfigPDA.toString()}`);
   console.log(`Current authority (wallet): ${provider.wallet.publicKey.toString()}`);
   console.log(`New manager authority: ${newManagerAuthority.toString()}`);
 
-  // Call the change_manager_authority function
+  // Nominate the new manager, it takes over once it signs accept_manager_authority
   try {
     const txSignature = await raydiumProgram.methods
-        .changeManagerAuthority(newManagerAuthority) // Call the correct method
+        .nominateManagerAuthority(newManagerAuthority)
         .accounts({
             authority: provider.wallet.publicKey, // Current manager authority MUST sign
             vaultConfig: vaultConfigPDA, // Reverted back to camelCase
@@ -38,17 +38,17 @@ import { vaultConfigSeed } from "./utils.ts"; // Removed .ts extension
     console.log("Transaction sent successfully!");
     console.log("Signature:", txSignature);
 
-    // Optional: Fetch the updated vault config to verify the change
+    // Optional: Fetch the updated vault config to verify the nomination
     const updatedVaultConfig = await raydiumProgram.account.vaultConfig.fetch(vaultConfigPDA);
-    console.log("Updated manager authority:", updatedVaultConfig.managerAuthority.toString());
-    if (updatedVaultConfig.managerAuthority.equals(newManagerAuthority)) {
-      console.log("Manager authority updated successfully!");
+    console.log("Pending manager authority:", updatedVaultConfig.pendingManagerAuthority.toString());
+    if (updatedVaultConfig.pendingManagerAuthority.equals(newManagerAuthority)) {
+      console.log("Manager nominated successfully! The new manager must run accept_vault_authority.");
     } else {
-      console.error("Verification failed: Manager authority did not update.");
+      console.error("Verification failed: Manager was not nominated.");
     }
 
   } catch (error) {
-    console.error("Error changing manager authority:", error);
+    console.error("Error nominating manager authority:", error);
     // Log specific AnchorError details if available
     if (error instanceof anchor.AnchorError) {
         console.error("AnchorError Code:", error.error.errorCode.code);
@@ -64,8 +64,6 @@ import BN from 'bn.js'; // Import BN for handling large numbers
       console.log("Pending Admin:", decodedAutofunConfig.pendingAuthority.toString());
       console.log("Team Wallet:", decodedAutofunConfig.teamWallet.toString());
       console.log("Init Bonding Curve:", decodedAutofunConfig.initBondingCurve);
-      console.log("Platform Buy Fee:", new BN(decodedAutofunConfig.platformBuyFee).toNumber());
-      console.log("Platform Sell Fee:", new BN(decodedAutofunConfig.platformSellFee).toNumber());
       console.log("Curve Limit:", new BN(decodedAutofunConfig.curveLimit).toNumber());
       console.log("Lamport Amount Config:", {
         min: new BN(decodedAutofunConfig.lamportAmountConfig.range.min).toNumber(),
@@ -93,6 +91,22 @@ import BN from 'bn.js'; // Import BN for handling large numbers
 
   console.log("Global WSOL Account PDA:", globalWsolAccountPDA.toBase58());
 
+  // Derive the fee config PDA using the seed "fee_config"
+  const [feeConfigPDA] = web3.PublicKey.findProgramAddressSync(
+    [Buffer.from("fee_config")],
+    autofunProgram.programId
+  );
+
+  const feeConfig = await autofunProgram.account.feeConfig.fetchNullable(feeConfigPDA);
+  if (!feeConfig) {
+    console.error("Fee config account not found for PDA:", feeConfigPDA.toBase58());
+  } else {
+    console.log("-------------------------------- Fee Config --------------------------------");
+    console.log("Fee Config PDA:", feeConfigPDA.toBase58());
+    console.log("Platform Buy Fee:", new BN(feeConfig.platformBuyFee).toNumber());
+    console.log("Platform Sell Fee:", new BN(feeConfig.platformSellFee).toNumber());
+  }
+
   if (!vaultAccountInfo) {
     console.error("Vault config account not found for PDA:", vaultConfigPDA.toBase58());
   } else {
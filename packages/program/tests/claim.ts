@@ -127,6 +127,23 @@ describe("raydium_vault", () => {
             true,
             spl.TOKEN_PROGRAM_ID
         );
+        // Fees are collected into vault-owned accounts, then distributed
+        // The vault token accounts are created by the first claim on each mint
+        const token0_program = (await connection.getAccountInfo(vault0_mint)).owner;
+        const token1_program = (await connection.getAccountInfo(vault1_mint)).owner;
+        const vault_token0_account = spl.getAssociatedTokenAddressSync(
+            vault0_mint,
+            vault_config,
+            true,
+            token0_program
+        );
+        const vault_token1_account = spl.getAssociatedTokenAddressSync(
+            vault1_mint,
+            vault_config,
+            true,
+            token1_program
+        );
+
         const locked_lp_vault = spl.getAssociatedTokenAddressSync(
             lp_mint,
             locked_authority,
@@ -154,6 +171,10 @@ describe("raydium_vault", () => {
                 lpMint: lp_mint,
                 recipientToken0Account: recv_token0_account, // move to raydium_vault contract
                 recipientToken1Account: recv_token1_account, // move to raydium_vault contract
+                vaultToken0Account: vault_token0_account,
+                vaultToken1Account: vault_token1_account,
+                protocolToken0Account: null, // required once a protocol fee is set
+                protocolToken1Account: null,
                 token0Vault: token0_vault,
                 token1Vault: token1_vault,
                 vault0Mint: vault0_mint,
@@ -161,7 +182,11 @@ describe("raydium_vault", () => {
                 lockedLpVault: locked_lp_vault,
                 tokenProgram: spl.TOKEN_PROGRAM_ID,
                 tokenProgram2022: spl.TOKEN_2022_PROGRAM_ID,
+                token0Program: token0_program,
+                token1Program: token1_program,
                 memoProgram: raydium_api.MEMO_PROGRAM_ID,
+                associatedTokenProgram: spl.ASSOCIATED_TOKEN_PROGRAM_ID,
+                systemProgram: anchor.web3.SystemProgram.programId,
             },
         });
     });
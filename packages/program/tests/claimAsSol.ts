@@ -0,0 +1,85 @@
+import * as anchor from "@coral-xyz/anchor";
+import { Program } from "@coral-xyz/anchor";
+import { assert } from "chai";
+import * as spl from "@solana/spl-token";
+import { RaydiumVault } from "../target/types/raydium_vault";
+import NodeWallet from "@coral-xyz/anchor/dist/cjs/nodewallet";
+import {
+    getNftAddress,
+    isDevnet,
+    loadRaydium,
+    solClaimSeed,
+    tokenBalance,
+    vaultPosition,
+} from "./utils";
+
+describe("raydium_vault", () => {
+    // Configure the client to use the local cluster.
+    const provider = anchor.AnchorProvider.env();
+    anchor.setProvider(provider);
+    const connection = provider.connection;
+
+    const nodeWallet = provider.wallet as NodeWallet;
+    const signerWallet = anchor.web3.Keypair.fromSecretKey(
+        nodeWallet.payer.secretKey
+    );
+
+    const program = anchor.workspace.RaydiumVault as Program<RaydiumVault>;
+
+    it("Claim Rewards As SOL", async function () {
+        const raydium = await loadRaydium(signerWallet);
+        const { position, cpmmPoolKey, accounts } = await vaultPosition(
+            program,
+            signerWallet,
+            raydium,
+            getNftAddress(isDevnet(connection)),
+            new anchor.web3.PublicKey("CExPZUmwAdu6dDZFGQmkxM8UFu1zVkGwk3tDVHWMifR9")
+        );
+        // Only the claimer can pick the swap's minimum output
+        if (!position.claimer.equals(signerWallet.publicKey)) {
+            this.skip();
+        }
+
+        const [sol_claim_account] = anchor.web3.PublicKey.findProgramAddressSync(
+            [Buffer.from(solClaimSeed), accounts.userPosition.toBuffer()],
+            program.programId
+        );
+        // Lowest lamports the token side swap may return, 0 accepts any price
+        const minimumAmountOut = new anchor.BN(process.env.MIN_SOL_OUT ?? 0);
+
+        const lamportsBefore = await connection.getBalance(position.claimer);
+        const vault0Before = await tokenBalance(accounts.vaultToken0Account);
+        const vault1Before = await tokenBalance(accounts.vaultToken1Account);
+
+        const { recipientToken0Account, recipientToken1Account, ...claimAccounts } = accounts;
+        const signature = await program.methods
+            .claimAsSol(minimumAmountOut)
+            .accountsPartial({
+                ...claimAccounts,
+                authority: signerWallet.publicKey,
+                ammConfig: new anchor.web3.PublicKey(cpmmPoolKey.config.id),
+                observationState: new anchor.web3.PublicKey(cpmmPoolKey.observationId),
+                wsolMint: spl.NATIVE_MINT,
+                solClaimAccount: sol_claim_account,
+            })
+            .rpc({ commitment: "confirmed" });
+
+        // The claimer signs, so the transaction fee comes out of what they receive
+        const tx = await connection.getTransaction(signature, {
+            commitment: "confirmed",
+            maxSupportedTransactionVersion: 0,
+        });
+        const received =
+            (await connection.getBalance(position.claimer)) - lamportsBefore + tx.meta.fee;
+        assert.isAtLeast(received, minimumAmountOut.toNumber());
+
+        // Everything collected is paid out, nothing is left in the vault or the temporary account
+        assert.equal((await tokenBalance(accounts.vaultToken0Account)).toString(), vault0Before.toString());
+        assert.equal((await tokenBalance(accounts.vaultToken1Account)).toString(), vault1Before.toString());
+        assert.isNull(await connection.getAccountInfo(sol_claim_account));
+
+        const after = await program.account.userPosition.fetch(accounts.userPosition);
+        assert.isTrue(after.totalClaimed0.gte(position.totalClaimed0));
+        assert.isTrue(after.totalClaimed1.gte(position.totalClaimed1));
+    });
+});
@@ -0,0 +1,182 @@
+import * as anchor from "@coral-xyz/anchor";
+import * as spl from "@solana/spl-token";
+import { Program } from "@coral-xyz/anchor";
+import { assert } from "chai";
+import { Raydium } from "@raydium-io/raydium-sdk-v2";
+import { RaydiumVault } from "../target/types/raydium_vault";
+import NodeWallet from "@coral-xyz/anchor/dist/cjs/nodewallet";
+import {
+    getNftAddress,
+    isDevnet,
+    loadRaydium,
+    tokenBalance,
+    vaultPosition,
+} from "./utils";
+
+describe("raydium_vault", () => {
+    // Configure the client to use the local cluster.
+    const provider = anchor.AnchorProvider.env();
+    anchor.setProvider(provider);
+    const connection = provider.connection;
+
+    const nodeWallet = provider.wallet as NodeWallet;
+    const signerWallet = anchor.web3.Keypair.fromSecretKey(
+        nodeWallet.payer.secretKey
+    );
+
+    const program = anchor.workspace.RaydiumVault as Program<RaydiumVault>;
+
+    const writable = (pubkey: anchor.web3.PublicKey) => ({
+        pubkey,
+        isSigner: false,
+        isWritable: true,
+    });
+    const readonly = (pubkey: anchor.web3.PublicKey) => ({
+        pubkey,
+        isSigner: false,
+        isWritable: false,
+    });
+
+    // Builds the remaining accounts group claim_many expects for one position
+    const positionGroup = async (
+        fixture: Awaited<ReturnType<typeof vaultPosition>>
+    ) => {
+        const { position, accounts } = fixture;
+        const vaultConfig = await program.account.vaultConfig.fetch(accounts.vaultConfig);
+
+        // claim_many does not create the vault token accounts
+        for (const [mint, tokenProgram] of [
+            [accounts.vault0Mint, accounts.token0Program],
+            [accounts.vault1Mint, accounts.token1Program],
+        ]) {
+            await spl.getOrCreateAssociatedTokenAccount(
+                connection,
+                signerWallet,
+                mint,
+                accounts.vaultConfig,
+                true,
+                "confirmed",
+                undefined,
+                tokenProgram
+            );
+        }
+
+        const group = [
+            writable(accounts.userPosition),
+            readonly(accounts.feeNftAccount),
+            writable(accounts.lockedLiquidity),
+            writable(accounts.poolState),
+            writable(accounts.lpMint),
+            writable(accounts.vaultToken0Account),
+            writable(accounts.vaultToken1Account),
+            writable(accounts.token0Vault),
+            writable(accounts.token1Vault),
+            readonly(accounts.vault0Mint),
+            readonly(accounts.vault1Mint),
+            writable(accounts.lockedLpVault),
+            writable(accounts.recipientToken0Account),
+            writable(accounts.recipientToken1Account),
+        ];
+        if (vaultConfig.protocolFeeBps > 0) {
+            for (const mint of [accounts.vault0Mint, accounts.vault1Mint]) {
+                group.push(
+                    writable(
+                        spl.getAssociatedTokenAddressSync(
+                            mint,
+                            vaultConfig.protocolFeeRecipient,
+                            true
+                        )
+                    )
+                );
+            }
+        }
+        for (const share of position.feeShares.slice(0, position.feeShareCount)) {
+            for (const mint of [accounts.vault0Mint, accounts.vault1Mint]) {
+                group.push(
+                    writable(
+                        spl.getAssociatedTokenAddressSync(mint, share.recipient, true)
+                    )
+                );
+            }
+        }
+        return group;
+    };
+
+    // Sends a claim_many batch and returns its BatchClaimed event
+    const claimMany = async (
+        accounts: Awaited<ReturnType<typeof vaultPosition>>["accounts"],
+        remainingAccounts: anchor.web3.AccountMeta[]
+    ) => {
+        const signature = await program.methods
+            .claimMany()
+            .accountsPartial({
+                authority: signerWallet.publicKey,
+                vaultConfig: accounts.vaultConfig,
+                lockingProgram: accounts.lockingProgram,
+                lockedAuthority: accounts.lockedAuthority,
+                cpmmProgram: accounts.cpmmProgram,
+                cpAuthority: accounts.cpAuthority,
+                tokenProgram: accounts.tokenProgram,
+                tokenProgram2022: accounts.tokenProgram2022,
+                memoProgram: accounts.memoProgram,
+            })
+            .remainingAccounts(remainingAccounts)
+            .rpc({ commitment: "confirmed" });
+
+        const tx = await connection.getTransaction(signature, {
+            commitment: "confirmed",
+            maxSupportedTransactionVersion: 0,
+        });
+        const parser = new anchor.EventParser(program.programId, program.coder);
+        for (const event of parser.parseLogs(tx.meta.logMessages)) {
+            if (event.name === "batchClaimed") {
+                return event.data as { claimed: number; skipped: number };
+            }
+        }
+        throw new Error("BatchClaimed not emitted");
+    };
+
+    // The test position nft and its cpmm pool
+    const loadPosition = async () => {
+        const raydium: Raydium = await loadRaydium(signerWallet);
+        return vaultPosition(
+            program,
+            signerWallet,
+            raydium,
+            getNftAddress(isDevnet(connection)),
+            new anchor.web3.PublicKey("CExPZUmwAdu6dDZFGQmkxM8UFu1zVkGwk3tDVHWMifR9")
+        );
+    };
+
+    it("Claim Many", async () => {
+        const fixture = await loadPosition();
+        const { position, accounts } = fixture;
+        const vault0Before = await tokenBalance(accounts.vaultToken0Account);
+        const vault1Before = await tokenBalance(accounts.vaultToken1Account);
+
+        const batch = await claimMany(accounts, await positionGroup(fixture));
+        assert.equal(batch.claimed + batch.skipped, 1);
+
+        // A claimed position's fees are paid out of the vault, a skipped one is left untouched
+        const after = await program.account.userPosition.fetch(accounts.userPosition);
+        if (batch.claimed === 1) {
+            assert.isTrue(after.lastUpdated.gt(position.lastUpdated));
+        } else {
+            assert.equal(after.lastUpdated.toString(), position.lastUpdated.toString());
+        }
+        assert.isTrue(after.totalClaimed0.gte(position.totalClaimed0));
+        assert.isTrue(after.totalClaimed1.gte(position.totalClaimed1));
+        assert.equal((await tokenBalance(accounts.vaultToken0Account)).toString(), vault0Before.toString());
+        assert.equal((await tokenBalance(accounts.vaultToken1Account)).toString(), vault1Before.toString());
+    });
+
+    it("Positions with no fees are skipped without failing the batch", async () => {
+        const fixture = await loadPosition();
+        const group = await positionGroup(fixture);
+
+        // The first group collects whatever fees are pending, so the repeated one has none left
+        const batch = await claimMany(fixture.accounts, [...group, ...group]);
+        assert.equal(batch.claimed + batch.skipped, 2);
+        assert.isAtLeast(batch.skipped, 1);
+    });
+});
@@ -0,0 +1,158 @@
+import * as anchor from "@coral-xyz/anchor";
+import * as spl from "@solana/spl-token";
+import { Program } from "@coral-xyz/anchor";
+import { assert } from "chai";
+import { RaydiumVault } from "../target/types/raydium_vault";
+import NodeWallet from "@coral-xyz/anchor/dist/cjs/nodewallet";
+import {
+    getNftAddress,
+    isDevnet,
+    loadRaydium,
+    lockedCpLiquiditySeed,
+    lockingProgram,
+    nftFaucetSeed,
+    positionSeed,
+    tokenBalance,
+    vaultPosition,
+} from "./utils";
+
+describe("raydium_vault", () => {
+    // Configure the client to use the local cluster.
+    const provider = anchor.AnchorProvider.env();
+    anchor.setProvider(provider);
+    const connection = provider.connection;
+
+    const nodeWallet = provider.wallet as NodeWallet;
+    const signerWallet = anchor.web3.Keypair.fromSecretKey(
+        nodeWallet.payer.secretKey
+    );
+
+    const program = anchor.workspace.RaydiumVault as Program<RaydiumVault>;
+
+    it("Compound Rewards", async () => {
+        const raydium = await loadRaydium(signerWallet);
+        const { position, accounts } = await vaultPosition(
+            program,
+            signerWallet,
+            raydium,
+            getNftAddress(isDevnet(connection)),
+            new anchor.web3.PublicKey("CExPZUmwAdu6dDZFGQmkxM8UFu1zVkGwk3tDVHWMifR9")
+        );
+        // Created by the first compound on the pool
+        const vault_lp_account = spl.getAssociatedTokenAddressSync(
+            accounts.lpMint,
+            accounts.vaultConfig,
+            true
+        );
+
+        // The new lock's fee nft, deposited back into the vault as a new position
+        const new_fee_nft_mint = anchor.web3.Keypair.generate();
+        const new_fee_nft_account = spl.getAssociatedTokenAddressSync(
+            new_fee_nft_mint.publicKey,
+            accounts.vaultConfig,
+            true
+        );
+        const [new_locked_liquidity] = anchor.web3.PublicKey.findProgramAddressSync(
+            [Buffer.from(lockedCpLiquiditySeed), new_fee_nft_mint.publicKey.toBuffer()],
+            lockingProgram
+        );
+        const metadata_program = new anchor.web3.PublicKey(
+            "metaqbxxUerdq28cj1RbAWkYQm3ybzjb6a8bt518x1s"
+        );
+        const [new_metadata_account] = anchor.web3.PublicKey.findProgramAddressSync(
+            [Buffer.from("metadata"), metadata_program.toBuffer(), new_fee_nft_mint.publicKey.toBuffer()],
+            metadata_program
+        );
+        const [new_user_position] = anchor.web3.PublicKey.findProgramAddressSync(
+            [Buffer.from(positionSeed), new_fee_nft_mint.publicKey.toBuffer()],
+            program.programId
+        );
+        const [new_nft_token_faucet] = anchor.web3.PublicKey.findProgramAddressSync(
+            [Buffer.from(nftFaucetSeed), new_fee_nft_mint.publicKey.toBuffer()],
+            program.programId
+        );
+
+        const recipient0Before = await tokenBalance(accounts.recipientToken0Account);
+        const recipient1Before = await tokenBalance(accounts.recipientToken1Account);
+        const vault0Before = await tokenBalance(accounts.vaultToken0Account);
+        const vault1Before = await tokenBalance(accounts.vaultToken1Account);
+
+        // Deposit amounts, worked out off-chain from the pending fees and pool reserves
+        const lpTokenAmount = new anchor.BN(process.env.LP_AMOUNT ?? 0);
+        const maximumToken0Amount = new anchor.BN(process.env.MAX_TOKEN_0 ?? 0);
+        const maximumToken1Amount = new anchor.BN(process.env.MAX_TOKEN_1 ?? 0);
+
+        const instruction = await program.methods
+            .compound(lpTokenAmount, maximumToken0Amount, maximumToken1Amount)
+            .accountsPartial({
+                ...accounts,
+                authority: signerWallet.publicKey,
+                vaultLpAccount: vault_lp_account,
+                newFeeNftMint: new_fee_nft_mint.publicKey,
+                newFeeNftAccount: new_fee_nft_account,
+                newLockedLiquidity: new_locked_liquidity,
+                newMetadataAccount: new_metadata_account,
+                newUserPosition: new_user_position,
+                newNftTokenFaucet: new_nft_token_faucet,
+                metadataProgram: metadata_program,
+                rent: anchor.web3.SYSVAR_RENT_PUBKEY,
+            })
+            .instruction();
+
+        // Too many accounts for a legacy transaction, so they go through a lookup table
+        const slot = await connection.getSlot("finalized");
+        const [createTableIx, lookupTable] =
+            anchor.web3.AddressLookupTableProgram.createLookupTable({
+                authority: signerWallet.publicKey,
+                payer: signerWallet.publicKey,
+                recentSlot: slot,
+            });
+        const extendTableIx = anchor.web3.AddressLookupTableProgram.extendLookupTable({
+            lookupTable,
+            authority: signerWallet.publicKey,
+            payer: signerWallet.publicKey,
+            addresses: instruction.keys
+                .filter((key) => !key.isSigner)
+                .map((key) => key.pubkey),
+        });
+        await provider.sendAndConfirm(
+            new anchor.web3.Transaction().add(createTableIx, extendTableIx)
+        );
+        // A table can be used from the slot after it was last extended
+        await new Promise((resolve) => setTimeout(resolve, 1000));
+        const table = (await connection.getAddressLookupTable(lookupTable)).value;
+
+        const { blockhash } = await connection.getLatestBlockhash();
+        const transaction = new anchor.web3.VersionedTransaction(
+            new anchor.web3.TransactionMessage({
+                payerKey: signerWallet.publicKey,
+                recentBlockhash: blockhash,
+                instructions: [
+                    anchor.web3.ComputeBudgetProgram.setComputeUnitLimit({ units: 1_000_000 }),
+                    instruction,
+                ],
+            }).compileToV0Message([table])
+        );
+        transaction.sign([signerWallet, new_fee_nft_mint]);
+        const signature = await connection.sendTransaction(transaction);
+        await connection.confirmTransaction(signature);
+        console.log("Compounded into", new_fee_nft_mint.publicKey.toString());
+
+        // The deposit is locked into a new position of the same claimer
+        const newPosition = await program.account.userPosition.fetch(new_user_position);
+        assert.ok(newPosition.claimer.equals(position.claimer));
+        assert.equal(newPosition.feeShareCount, 0);
+        assert.equal(newPosition.amount, 1);
+        assert.equal((await tokenBalance(new_nft_token_faucet)).toString(), "1");
+        assert.equal((await tokenBalance(vault_lp_account)).toString(), "0");
+
+        // Whatever the deposit did not take is paid out, nothing stays in the vault
+        assert.isTrue((await tokenBalance(accounts.recipientToken0Account)).gte(recipient0Before));
+        assert.isTrue((await tokenBalance(accounts.recipientToken1Account)).gte(recipient1Before));
+        assert.equal((await tokenBalance(accounts.vaultToken0Account)).toString(), vault0Before.toString());
+        assert.equal((await tokenBalance(accounts.vaultToken1Account)).toString(), vault1Before.toString());
+        const after = await program.account.userPosition.fetch(accounts.userPosition);
+        assert.isTrue(after.totalClaimed0.gte(position.totalClaimed0));
+        assert.isTrue(after.totalClaimed1.gte(position.totalClaimed1));
+    });
+});
@@ -66,14 +66,16 @@ console.log("ENV: ", process.env.ENV);
     pendingAuthority: new anchor.web3.PublicKey("autozgbVb1EvhrTZTkpLekJRN4sN5hhGYpMMiY9kQ5S"),
     teamWallet: new anchor.web3.PublicKey("autovtovm7oqwtbyrWgdSH7i1W4nLPRWjXM2wcdqn1R"),
     initBondingCurve: 100,
-    platformBuyFee: new BN(100),
-    platformSellFee: new BN(100),
     curveLimit: new BN(process.env.ENV === "dev" ? 11300000000 : 113000000000),
     lamportAmountConfig: { range: { min: new BN(0.01 * anchor.web3.LAMPORTS_PER_SOL), max: new BN(100 * anchor.web3.LAMPORTS_PER_SOL) } },
     tokenSupplyConfig: { range: { min: new BN(5000), max: new BN(1000000000000000) } },
     tokenDecimalsConfig: { range: { min: 6, max: 9 } }
   };
 
+  // Swap fees in basis points, stored in the fee_config account
+  const platformBuyFee = new BN(100);
+  const platformSellFee = new BN(100);
+
   // Add logging to verify the configuration object
   console.log("New Config:", newConfig);
   console.log("Config PDA:", configPDA.toBase58());
@@ -93,7 +95,7 @@ console.log("ENV: ", process.env.ENV);
 
   // Send the configure transaction.
   try {
-    const txSignature = await program.methods.configure(newConfig).accounts(accounts).rpc();
+    const txSignature = await program.methods.configure(newConfig, platformBuyFee, platformSellFee).accounts(accounts).rpc();
 
     console.log("Transaction sent successfully!");
     console.log("Signature:", txSignature);
@@ -43,18 +43,21 @@ import NodeWallet from "@coral-xyz/anchor/dist/cjs/nodewallet";
   // Send the transaction to launch a token
   try {
     const tx = await program.methods
-      .launch(
+      .launch({
         decimals,
-        token_supply,
-        virtual_lamport_reserves,
+        tokenSupply: token_supply,
+        virtualLamportReserves: virtual_lamport_reserves,
         name,
         symbol,
-        uri
-      )
+        uri,
+        tradingStartsAt: null, // trade immediately
+        expiresAt: null, // never expires
+      })
       .accounts({
         creator: provider.wallet.publicKey,
         token: tokenKp.publicKey,
         teamWallet: configAccount.teamWallet,
+        quoteConfig: null, // SOL curve
       })
       .signers([signerWallet, tokenKp])
       .rpc();
@@ -0,0 +1,210 @@
+import * as anchor from "@coral-xyz/anchor";
+import * as spl from "@solana/spl-token";
+import { Program, BN } from "@coral-xyz/anchor";
+import { assert } from "chai";
+import { Autofun } from "../target/types/autofun";
+import NodeWallet from "@coral-xyz/anchor/dist/cjs/nodewallet";
+import { connection, createAta, fundWallet, launchCurve, swapV2 } from "./utils";
+
+// Must match PRICE_SCALE in programs/autofun/src/state.rs
+const PRICE_SCALE = new BN("1000000000000");
+
+describe("autofun", () => {
+    const provider = anchor.AnchorProvider.env();
+    anchor.setProvider(provider);
+
+    const nodeWallet = provider.wallet as NodeWallet;
+    const keeper = anchor.web3.Keypair.fromSecretKey(nodeWallet.payer.secretKey);
+
+    const program = anchor.workspace.Autofun as Program<Autofun>;
+
+    const owner = anchor.web3.Keypair.generate();
+    const keeperTip = new BN(1_000_000);
+
+    let curve: Awaited<ReturnType<typeof launchCurve>>;
+    let ownerAta: anchor.web3.PublicKey;
+    let nonce = 0;
+
+    const spotPrice = async () => {
+        const account = await program.account.bondingCurve.fetch(curve.bondingCurve);
+        return account.reserveLamport.mul(PRICE_SCALE).div(account.reserveToken);
+    };
+
+    const orderAccounts = (orderNonce: BN) => {
+        const [order] = anchor.web3.PublicKey.findProgramAddressSync(
+            [
+                Buffer.from("order"),
+                curve.bondingCurve.toBuffer(),
+                owner.publicKey.toBuffer(),
+                orderNonce.toArrayLike(Buffer, "le", 8),
+            ],
+            program.programId
+        );
+        const orderAta = spl.getAssociatedTokenAddressSync(curve.tokenMint, order, true);
+        return { order, orderAta };
+    };
+
+    const placeOrder = async (
+        direction: any,
+        amount: BN,
+        limitPrice: BN,
+        expiresAt = Math.floor(Date.now() / 1000) + 600
+    ) => {
+        const orderNonce = new BN(nonce++);
+        const { order, orderAta } = orderAccounts(orderNonce);
+        const isSell = "sell" in direction;
+        await program.methods
+            .placeOrder(orderNonce, {
+                direction,
+                amount,
+                limitPrice,
+                keeperTip,
+                expiresAt: new BN(expiresAt),
+            })
+            .accountsPartial({
+                owner: owner.publicKey,
+                tokenMint: curve.tokenMint,
+                bondingCurve: curve.bondingCurve,
+                order,
+                orderTokenAccount: isSell ? orderAta : null,
+                ownerTokenAccount: ownerAta,
+            })
+            .signers([owner])
+            .rpc({ commitment: "confirmed" });
+        return { order, orderAta: isSell ? orderAta : null };
+    };
+
+    const fillOrder = ({ order, orderAta }: Awaited<ReturnType<typeof placeOrder>>) =>
+        program.methods
+            .fillOrder()
+            .accountsPartial({
+                bondingCurve: curve.bondingCurve,
+                feeVault: curve.feeVault,
+                globalVault: curve.globalVault,
+                tokenMint: curve.tokenMint,
+                globalAta: curve.globalAta,
+                order,
+                orderTokenAccount: orderAta,
+                ownerTokenAccount: ownerAta,
+                owner: owner.publicKey,
+                keeper: keeper.publicKey,
+            })
+            .rpc({ commitment: "confirmed" });
+
+    const cancelOrder = ({ order, orderAta }: Awaited<ReturnType<typeof placeOrder>>) =>
+        program.methods
+            .cancelOrder()
+            .accountsPartial({
+                order,
+                tokenMint: curve.tokenMint,
+                orderTokenAccount: orderAta,
+                ownerTokenAccount: ownerAta,
+                owner: owner.publicKey,
+            })
+            .signers([owner])
+            .rpc({ commitment: "confirmed" });
+
+    const tokenBalance = async () =>
+        new BN((await spl.getAccount(connection, ownerAta)).amount.toString());
+
+    before(async () => {
+        curve = await launchCurve(program, keeper);
+        await fundWallet(keeper, owner.publicKey, 10 * anchor.web3.LAMPORTS_PER_SOL);
+        ownerAta = await createAta(keeper, curve.tokenMint, owner.publicKey);
+        // Tokens for the sell orders
+        await swapV2(program, curve, owner, { buy: {} }, new BN(anchor.web3.LAMPORTS_PER_SOL));
+    });
+
+    it("Buy order without a token account fills once triggered", async () => {
+        const placed = await placeOrder(
+            { buy: {} },
+            new BN(100_000_000),
+            (await spotPrice()).muln(2)
+        );
+        assert.isNull(await connection.getAccountInfo(orderAccounts(new BN(0)).orderAta));
+
+        const tokensBefore = await tokenBalance();
+        const keeperBefore = await connection.getBalance(keeper.publicKey);
+        await fillOrder(placed);
+
+        assert.isTrue((await tokenBalance()).gt(tokensBefore));
+        assert.isNull(await connection.getAccountInfo(placed.order));
+        // The keeper pays the transaction fee out of the tip
+        assert.equal(
+            (await connection.getBalance(keeper.publicKey)) - keeperBefore,
+            keeperTip.toNumber() - 5000
+        );
+    });
+
+    it("Sell order above the price does not fill and cancels back to the owner", async () => {
+        const tokensBefore = await tokenBalance();
+        const amount = tokensBefore.divn(2);
+        const placed = await placeOrder({ sell: {} }, amount, (await spotPrice()).muln(2));
+
+        const escrow = await spl.getAccount(connection, placed.orderAta);
+        assert.equal(escrow.amount.toString(), amount.toString());
+
+        try {
+            await fillOrder(placed);
+            assert.fail("filled an untriggered order");
+        } catch (err) {
+            assert.equal(err.error?.errorCode?.code, "OrderNotTriggered");
+        }
+
+        await cancelOrder(placed);
+        assert.equal((await tokenBalance()).toString(), tokensBefore.toString());
+        assert.isNull(await connection.getAccountInfo(placed.order));
+        assert.isNull(await connection.getAccountInfo(placed.orderAta));
+    });
+
+    it("Sell order fills at or above its limit price", async () => {
+        const amount = (await tokenBalance()).divn(2);
+        const limitPrice = (await spotPrice()).divn(2);
+        const placed = await placeOrder({ sell: {} }, amount, limitPrice);
+
+        const lamportsBefore = await connection.getBalance(owner.publicKey);
+        await fillOrder(placed);
+
+        // The owner also gets the rent of the order and its token account back
+        const received = new BN((await connection.getBalance(owner.publicKey)) - lamportsBefore);
+        assert.isTrue(received.gte(amount.mul(limitPrice).div(PRICE_SCALE)));
+        assert.isNull(await connection.getAccountInfo(placed.orderAta));
+    });
+
+    it("Dust sent to a sell order's escrow does not block the fill", async () => {
+        const tokensBefore = await tokenBalance();
+        const amount = tokensBefore.divn(2);
+        const placed = await placeOrder({ sell: {} }, amount, (await spotPrice()).divn(2));
+
+        // Anyone can send tokens to the escrow, the extra goes back to the owner on fill
+        await spl.transfer(connection, owner, ownerAta, placed.orderAta, owner, 1);
+        await fillOrder(placed);
+
+        assert.isNull(await connection.getAccountInfo(placed.orderAta));
+        assert.equal((await tokenBalance()).toString(), tokensBefore.sub(amount).toString());
+    });
+
+    it("Expired order cannot fill but can still be cancelled", async () => {
+        const expiresAt = Math.floor(Date.now() / 1000) + 3;
+        const placed = await placeOrder(
+            { buy: {} },
+            new BN(100_000_000),
+            (await spotPrice()).muln(2),
+            expiresAt
+        );
+
+        while (Math.floor(Date.now() / 1000) <= expiresAt) {
+            await new Promise((resolve) => setTimeout(resolve, 1000));
+        }
+
+        try {
+            await fillOrder(placed);
+            assert.fail("filled an expired order");
+        } catch (err) {
+            assert.equal(err.error?.errorCode?.code, "OrderExpired");
+        }
+
+        await cancelOrder(placed);
+        assert.isNull(await connection.getAccountInfo(placed.order));
+    });
+});
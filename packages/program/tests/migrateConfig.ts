@@ -0,0 +1,39 @@
+import * as anchor from '@coral-xyz/anchor';
+import { Program, web3 } from '@coral-xyz/anchor';
+import type { Autofun } from '../target/types/autofun.ts';
+
+(async () => {
+  console.log("Starting migrateConfig");
+
+  // Must be the config authority
+  const provider = anchor.AnchorProvider.env();
+  anchor.setProvider(provider);
+
+  // Instantiate the program using the IDL and programId.
+  const program = anchor.workspace.Autofun as Program<Autofun>;
+  console.log("Using Autofun program ID:", program.programId.toBase58());
+
+  const [feeConfigPDA] = web3.PublicKey.findProgramAddressSync(
+    [Buffer.from('fee_config')],
+    program.programId
+  );
+
+  // Moves the legacy config's swap fees to fee_config, only runs once
+  try {
+    const txSignature = await program.methods
+      .migrateConfig()
+      .accounts({
+        authority: provider.wallet.publicKey,
+      })
+      .rpc();
+
+    console.log("Transaction sent successfully!");
+    console.log("Signature:", txSignature);
+
+    const feeConfig = await program.account.feeConfig.fetch(feeConfigPDA);
+    console.log("Platform Buy Fee:", feeConfig.platformBuyFee.toNumber());
+    console.log("Platform Sell Fee:", feeConfig.platformSellFee.toNumber());
+  } catch (err) {
+    console.error("Migrate config transaction failed:", err);
+  }
+})();
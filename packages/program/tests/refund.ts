@@ -0,0 +1,134 @@
+import * as anchor from "@coral-xyz/anchor";
+import * as spl from "@solana/spl-token";
+import { Program, BN } from "@coral-xyz/anchor";
+import { assert } from "chai";
+import { Autofun } from "../target/types/autofun";
+import NodeWallet from "@coral-xyz/anchor/dist/cjs/nodewallet";
+import { connection, createAta, fundWallet, launchCurve, swapV2 } from "./utils";
+
+const sleepUntil = async (timestamp: number) => {
+    while (Math.floor(Date.now() / 1000) <= timestamp) {
+        await new Promise((resolve) => setTimeout(resolve, 1000));
+    }
+};
+
+describe("autofun", () => {
+    const provider = anchor.AnchorProvider.env();
+    anchor.setProvider(provider);
+
+    const nodeWallet = provider.wallet as NodeWallet;
+    const signerWallet = anchor.web3.Keypair.fromSecretKey(
+        nodeWallet.payer.secretKey
+    );
+
+    const program = anchor.workspace.Autofun as Program<Autofun>;
+
+    const buyer = anchor.web3.Keypair.generate();
+    // Holds tokens it did not buy, like the team allocation minted at launch
+    const holder = anchor.web3.Keypair.generate();
+
+    let curve: Awaited<ReturnType<typeof launchCurve>>;
+
+    const traderRecord = (trader: anchor.web3.Keypair) =>
+        anchor.web3.PublicKey.findProgramAddressSync(
+            [Buffer.from("trader_record"), curve.bondingCurve.toBuffer(), trader.publicKey.toBuffer()],
+            program.programId
+        )[0];
+
+    const tokenAccount = (trader: anchor.web3.Keypair) =>
+        spl.getAssociatedTokenAddressSync(curve.tokenMint, trader.publicKey);
+
+    const refund = (trader: anchor.web3.Keypair, amount: BN) =>
+        program.methods
+            .refund(amount)
+            .accountsPartial({
+                tokenMint: curve.tokenMint,
+                bondingCurve: curve.bondingCurve,
+                globalVault: curve.globalVault,
+                userTokenAccount: tokenAccount(trader),
+                user: trader.publicKey,
+                traderRecord: traderRecord(trader),
+                quoteVault: null,
+                userQuoteAccount: null,
+            })
+            .signers([trader])
+            .rpc({ commitment: "confirmed" });
+
+    const balance = async (trader: anchor.web3.Keypair) =>
+        new BN((await spl.getAccount(connection, tokenAccount(trader))).amount.toString());
+
+    let expiresAt: number;
+
+    before(async () => {
+        expiresAt = Math.floor(Date.now() / 1000) + 20;
+        curve = await launchCurve(program, signerWallet, { expiresAt: new BN(expiresAt) });
+        for (const trader of [buyer, holder]) {
+            await fundWallet(signerWallet, trader.publicKey, anchor.web3.LAMPORTS_PER_SOL);
+            await createAta(signerWallet, curve.tokenMint, trader.publicKey);
+        }
+
+        await swapV2(program, curve, buyer, { buy: {} }, new BN(100_000_000));
+        await swapV2(program, curve, holder, { buy: {} }, new BN(1_000_000));
+
+        // Half of the buyer's tokens move to the holder, who never bought them
+        const bought = await balance(buyer);
+        await spl.transfer(
+            connection,
+            buyer,
+            tokenAccount(buyer),
+            tokenAccount(holder),
+            buyer,
+            BigInt(bought.divn(2).toString())
+        );
+
+        await sleepUntil(expiresAt);
+    });
+
+    it("Tokens not bought off the curve cannot be refunded", async () => {
+        try {
+            await refund(holder, await balance(holder));
+            assert.fail("refunded tokens received by transfer");
+        } catch (err) {
+            assert.equal(err.error?.errorCode?.code, "RefundExceedsBought");
+        }
+
+        // The holder's own purchase still refunds
+        const record = await program.account.traderRecord.fetch(traderRecord(holder));
+        await refund(holder, record.boughtToken);
+        const after = await program.account.traderRecord.fetch(traderRecord(holder));
+        assert.equal(after.boughtToken.toNumber(), 0);
+    });
+
+    it("Only the trader closes a record that still backs a refund", async () => {
+        const closeTraderRecord = (signers: anchor.web3.Keypair[]) =>
+            program.methods
+                .closeTraderRecord()
+                .accountsPartial({
+                    tokenMint: curve.tokenMint,
+                    bondingCurve: curve.bondingCurve,
+                    traderRecord: traderRecord(buyer),
+                    user: buyer.publicKey,
+                })
+                // The trader signing makes them a signer of the user account
+                .signers(signers)
+                .rpc({ commitment: "confirmed" });
+
+        try {
+            await closeTraderRecord([]);
+            assert.fail("closed a record that backs a refund");
+        } catch (err) {
+            assert.equal(err.error?.errorCode?.code, "RefundPending");
+        }
+
+        // The buyer refunds what they still hold, the rest of their cap stays unused
+        const lamportsBefore = await connection.getBalance(buyer.publicKey);
+        await refund(buyer, await balance(buyer));
+        assert.isAbove(await connection.getBalance(buyer.publicKey), lamportsBefore);
+        assert.equal((await balance(buyer)).toNumber(), 0);
+        const record = await program.account.traderRecord.fetch(traderRecord(buyer));
+        assert.isAbove(record.boughtToken.toNumber(), 0);
+
+        await closeTraderRecord([buyer]);
+        assert.isNull(await connection.getAccountInfo(traderRecord(buyer)));
+    });
+});
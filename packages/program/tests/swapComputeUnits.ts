@@ -0,0 +1,86 @@
+import * as anchor from "@coral-xyz/anchor";
+import * as spl from "@solana/spl-token";
+import { Program, BN } from "@coral-xyz/anchor";
+import { assert } from "chai";
+import { Autofun } from "../target/types/autofun";
+import NodeWallet from "@coral-xyz/anchor/dist/cjs/nodewallet";
+import { computeUnits, createAta, fundWallet, launchCurve, swapV2 } from "./utils";
+
+// Must match SWAP_V2_COMPUTE_BUDGET in programs/autofun/src/constants.rs
+const SWAP_V2_COMPUTE_BUDGET = 45_000;
+
+describe("autofun", () => {
+    const provider = anchor.AnchorProvider.env();
+    anchor.setProvider(provider);
+    const connection = provider.connection;
+
+    const nodeWallet = provider.wallet as NodeWallet;
+    const signerWallet = anchor.web3.Keypair.fromSecretKey(
+        nodeWallet.payer.secretKey
+    );
+
+    const program = anchor.workspace.Autofun as Program<Autofun>;
+
+    // A trader that has never traded on the curve, so its first swap creates a TraderRecord
+    const trader = anchor.web3.Keypair.generate();
+
+    let curve: Awaited<ReturnType<typeof launchCurve>>;
+    let traderAta: anchor.web3.PublicKey;
+
+    const swapUnits = async (direction: any, amount: BN) =>
+        computeUnits(await swapV2(program, curve, trader, direction, amount));
+
+    before(async () => {
+        curve = await launchCurve(program, signerWallet);
+        const curveAccount = await program.account.bondingCurve.fetch(curve.bondingCurve);
+
+        // Enough to complete the curve, plus rent and fees
+        await fundWallet(
+            signerWallet,
+            trader.publicKey,
+            curveAccount.curveLimit.toNumber() + anchor.web3.LAMPORTS_PER_SOL
+        );
+        traderAta = await createAta(signerWallet, curve.tokenMint, trader.publicKey);
+    });
+
+    it("First buy by a new trader stays within the compute budget", async function () {
+        const units = await swapUnits({ buy: {} }, new BN(1_000_000));
+        console.log("swap_v2 first buy compute units:", units);
+        assert.isAtMost(units, SWAP_V2_COMPUTE_BUDGET);
+
+        const curveAccount = await program.account.bondingCurve.fetch(curve.bondingCurve);
+        assert.equal(curveAccount.uniqueTraderCount.toNumber(), 1);
+    });
+
+    it("Buy stays within the compute budget", async function () {
+        const units = await swapUnits({ buy: {} }, new BN(1_000_000));
+        console.log("swap_v2 buy compute units:", units);
+        assert.isAtMost(units, SWAP_V2_COMPUTE_BUDGET);
+    });
+
+    it("Sell stays within the compute budget", async function () {
+        const account = await spl.getAccount(connection, traderAta);
+        const units = await swapUnits({ sell: {} }, new BN(account.amount.toString()));
+        console.log("swap_v2 sell compute units:", units);
+        assert.isAtMost(units, SWAP_V2_COMPUTE_BUDGET);
+    });
+
+    it("Completing buy stays within the compute budget", async function () {
+        const before = await program.account.bondingCurve.fetch(curve.bondingCurve);
+        // Buys above the curve limit are capped to what completes it
+        const signature = await swapV2(program, curve, trader, { buy: {} }, before.curveLimit);
+        const units = await computeUnits(signature);
+        console.log("swap_v2 completing buy compute units:", units);
+        assert.isAtMost(units, SWAP_V2_COMPUTE_BUDGET);
+
+        const after = await program.account.bondingCurve.fetch(curve.bondingCurve);
+        assert.equal(after.isCompleted, 1);
+
+        // The completion is reported through CompleteEvent only, never a program log
+        const tx = await connection.getTransaction(signature, {
+            commitment: "confirmed",
+            maxSupportedTransactionVersion: 0,
+        });
+        assert.isFalse(tx.meta.logMessages.some((log) => log.includes("curve is completed")));
+    });
+});
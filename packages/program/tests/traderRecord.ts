@@ -0,0 +1,104 @@
+import * as anchor from "@coral-xyz/anchor";
+import { Program, BN } from "@coral-xyz/anchor";
+import { assert } from "chai";
+import { Autofun } from "../target/types/autofun";
+import NodeWallet from "@coral-xyz/anchor/dist/cjs/nodewallet";
+import { connection, createAta, fundWallet, launchCurve, swapV2 } from "./utils";
+
+describe("autofun", () => {
+    const provider = anchor.AnchorProvider.env();
+    anchor.setProvider(provider);
+
+    const nodeWallet = provider.wallet as NodeWallet;
+    const signerWallet = anchor.web3.Keypair.fromSecretKey(
+        nodeWallet.payer.secretKey
+    );
+
+    const program = anchor.workspace.Autofun as Program<Autofun>;
+
+    const trader = anchor.web3.Keypair.generate();
+
+    let curve: Awaited<ReturnType<typeof launchCurve>>;
+    let traderRecord: anchor.web3.PublicKey;
+
+    const closeTraderRecord = () =>
+        program.methods
+            .closeTraderRecord()
+            .accountsPartial({
+                tokenMint: curve.tokenMint,
+                bondingCurve: curve.bondingCurve,
+                traderRecord,
+                user: trader.publicKey,
+            })
+            .rpc({ commitment: "confirmed" });
+
+    before(async () => {
+        curve = await launchCurve(program, signerWallet);
+        [traderRecord] = anchor.web3.PublicKey.findProgramAddressSync(
+            [
+                Buffer.from("trader_record"),
+                curve.bondingCurve.toBuffer(),
+                trader.publicKey.toBuffer(),
+            ],
+            program.programId
+        );
+
+        const curveAccount = await program.account.bondingCurve.fetch(curve.bondingCurve);
+        await fundWallet(
+            signerWallet,
+            trader.publicKey,
+            curveAccount.curveLimit.toNumber() + anchor.web3.LAMPORTS_PER_SOL
+        );
+        await createAta(signerWallet, curve.tokenMint, trader.publicKey);
+    });
+
+    it("First trade creates the record at the trader's expense", async () => {
+        const balanceBefore = await connection.getBalance(trader.publicKey);
+        const amount = 1_000_000;
+        await swapV2(program, curve, trader, { buy: {} }, new BN(amount));
+
+        const record = await program.account.traderRecord.fetch(traderRecord);
+        assert.ok(record.user.equals(trader.publicKey));
+        assert.ok(record.bondingCurve.equals(curve.bondingCurve));
+        assert.isAbove(record.firstTradeTs.toNumber(), 0);
+        assert.isAbove(record.boughtToken.toNumber(), 0);
+
+        const rent = await connection.getBalance(traderRecord);
+        const balanceAfter = await connection.getBalance(trader.publicKey);
+        // The buy amount and fees come on top of the rent
+        assert.isAtLeast(balanceBefore - balanceAfter, amount + rent);
+
+        const curveAccount = await program.account.bondingCurve.fetch(curve.bondingCurve);
+        assert.equal(curveAccount.uniqueTraderCount.toNumber(), 1);
+    });
+
+    it("Later trades do not count the trader again", async () => {
+        await swapV2(program, curve, trader, { buy: {} }, new BN(1_000_000));
+
+        const curveAccount = await program.account.bondingCurve.fetch(curve.bondingCurve);
+        assert.equal(curveAccount.uniqueTraderCount.toNumber(), 1);
+        assert.equal(curveAccount.tradeCount.toNumber(), 2);
+    });
+
+    it("Record cannot be closed while the curve trades", async () => {
+        try {
+            await closeTraderRecord();
+            assert.fail("closed a record of a trading curve");
+        } catch (err) {
+            assert.equal(err.error?.errorCode?.code, "CurveStillTrading");
+        }
+    });
+
+    it("Record closes to the trader once the curve completes", async () => {
+        const curveAccount = await program.account.bondingCurve.fetch(curve.bondingCurve);
+        await swapV2(program, curve, trader, { buy: {} }, curveAccount.curveLimit);
+
+        const rent = await connection.getBalance(traderRecord);
+        const balanceBefore = await connection.getBalance(trader.publicKey);
+        // Anyone can close it, the provider wallet pays the fee
+        await closeTraderRecord();
+
+        assert.isNull(await connection.getAccountInfo(traderRecord));
+        assert.equal(await connection.getBalance(trader.publicKey), balanceBefore + rent);
+    });
+});
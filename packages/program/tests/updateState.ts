@@ -41,12 +41,12 @@ describe("raydium_vault", () => {
         );
     });
 
-    it("Update executor, emergency and manager authority", async function () {
+    it("Nominate executor, emergency and manager authority", async function () {
         const [vaultConfig] = anchor.web3.PublicKey.findProgramAddressSync(
             [Buffer.from(vaultConfigSeed)],
             program.programId
         );
-        console.log("Update Executor Wallet...");
+        console.log("Nominate Executor Wallet...");
         let newExecutorAddress = new anchor.web3.PublicKey(
             "6HHoqvXfNF1aQpwhn4k13CL7iyzFpjghLhG2eBG6xMVV"
         );
@@ -56,23 +56,23 @@ describe("raydium_vault", () => {
         let newManagerAddress = new anchor.web3.PublicKey(
             "6HHoqvXfNF1aQpwhn4k13CL7iyzFpjghLhG2eBG6xMVV"
         );
-        await program.rpc.changeExecutorAuthority(newExecutorAddress, {
+        await program.rpc.nominateExecutorAuthority(newExecutorAddress, {
             accounts: {
                 authority: signerWallet.publicKey,
                 vaultConfig: vaultConfig,
             },
         });
 
-        console.log("Update Emergency Wallet...");
-        await program.rpc.changeEmergencyAuthority(newEmergencyAddress, {
+        console.log("Nominate Emergency Wallet...");
+        await program.rpc.nominateEmergencyAuthority(newEmergencyAddress, {
             accounts: {
                 authority: signerWallet.publicKey,
                 vaultConfig: vaultConfig,
             },
         });
 
-        console.log("Update Manager Wallet...");
-        await program.rpc.changeManagerAuthority(newManagerAddress, {
+        console.log("Nominate Manager Wallet...");
+        await program.rpc.nominateManagerAuthority(newManagerAddress, {
             accounts: {
                 authority: signerWallet.publicKey,
                 vaultConfig: vaultConfig,
@@ -95,5 +95,17 @@ describe("raydium_vault", () => {
             "manager authority: ",
             vaultConfigInfo.managerAuthority.toString()
         );
+        console.log(
+            "pending executor authority: ",
+            vaultConfigInfo.pendingExecutorAuthority.toString()
+        );
+        console.log(
+            "pending emergency authority: ",
+            vaultConfigInfo.pendingEmergencyAuthority.toString()
+        );
+        console.log(
+            "pending manager authority: ",
+            vaultConfigInfo.pendingManagerAuthority.toString()
+        );
     });
 });
@@ -1,10 +1,14 @@
 import * as anchor from "@coral-xyz/anchor";
+import { BN, Program } from "@coral-xyz/anchor";
 import * as spl from "@solana/spl-token";
 import {
     sendAndConfirmTransaction,
     SystemProgram,
     Transaction,
 } from "@solana/web3.js";
+import { CREATE_CPMM_POOL_PROGRAM, MEMO_PROGRAM_ID, Raydium } from "@raydium-io/raydium-sdk-v2";
+import { Autofun } from "../target/types/autofun";
+import { RaydiumVault } from "../target/types/raydium_vault";
 
 const provider = anchor.AnchorProvider.env();
 anchor.setProvider(provider);
@@ -15,6 +19,8 @@ export const vaultConfigSeed = "raydium_vault_config";
 export const positionSeed = "raydium_position";
 export const claimerInfoSeed = "raydium_claimer_info";
 export const nftFaucetSeed = "raydium_vault_nft_seed";
+export const solClaimSeed = "raydium_vault_sol_claim";
+export const emergencyWithdrawSeed = "raydium_vault_emergency_withdraw";
 
 /// USDC
 const token0 = new anchor.web3.PublicKey(
@@ -120,6 +126,275 @@ export const sendTokenTo = async (
     let afterBobBal = await connection.getTokenAccountBalance(bobTokenAccount);
 };
 
+/// Sends lamports to a test wallet without logging balances
+export const fundWallet = async (
+    signerWallet: anchor.web3.Keypair,
+    recvWallet: anchor.web3.PublicKey,
+    lamports: number
+) => {
+    await sendAndConfirmTransaction(
+        connection,
+        new Transaction().add(
+            SystemProgram.transfer({
+                fromPubkey: signerWallet.publicKey,
+                toPubkey: recvWallet,
+                lamports,
+            })
+        ),
+        [signerWallet],
+        { commitment: "confirmed" }
+    );
+};
+
+/// Creates the ATA of a test wallet if it does not exist yet
+export const createAta = async (
+    payer: anchor.web3.Keypair,
+    mint: anchor.web3.PublicKey,
+    owner: anchor.web3.PublicKey
+) => {
+    const ata = spl.getAssociatedTokenAddressSync(mint, owner, true);
+    await sendAndConfirmTransaction(
+        connection,
+        new Transaction().add(
+            spl.createAssociatedTokenAccountIdempotentInstruction(
+                payer.publicKey,
+                ata,
+                owner,
+                mint
+            )
+        ),
+        [payer],
+        { commitment: "confirmed" }
+    );
+    return ata;
+};
+
+/// Launches a fresh autofun SOL curve for a test, returns its mint and PDAs
+export const launchCurve = async (
+    program: Program<Autofun>,
+    creator: anchor.web3.Keypair,
+    options: { tradingStartsAt?: BN; expiresAt?: BN } = {}
+) => {
+    const [configPda] = anchor.web3.PublicKey.findProgramAddressSync(
+        [Buffer.from("config")],
+        program.programId
+    );
+    const configAccount = await program.account.config.fetch(configPda);
+
+    const token = anchor.web3.Keypair.generate();
+    await program.methods
+        .launch({
+            decimals: 6,
+            tokenSupply: new BN(1_000_000_000_000_000),
+            virtualLamportReserves: new BN(280_000_000),
+            name: "Test Token",
+            symbol: "TEST",
+            uri: "https://example.com/metadata.json",
+            tradingStartsAt: options.tradingStartsAt ?? null,
+            expiresAt: options.expiresAt ?? null,
+        })
+        .accounts({
+            creator: creator.publicKey,
+            token: token.publicKey,
+            teamWallet: configAccount.teamWallet,
+            quoteConfig: null,
+        })
+        .signers([creator, token])
+        .rpc({ commitment: "confirmed" });
+
+    return autofunAccounts(program, token.publicKey);
+};
+
+/// PDAs of an autofun SOL curve
+export const autofunAccounts = (
+    program: Program<Autofun>,
+    tokenMint: anchor.web3.PublicKey
+) => {
+    const [bondingCurve] = anchor.web3.PublicKey.findProgramAddressSync(
+        [Buffer.from("bonding_curve"), tokenMint.toBuffer()],
+        program.programId
+    );
+    const [globalVault] = anchor.web3.PublicKey.findProgramAddressSync(
+        [Buffer.from("global")],
+        program.programId
+    );
+    const [feeConfig] = anchor.web3.PublicKey.findProgramAddressSync(
+        [Buffer.from("fee_config")],
+        program.programId
+    );
+    const [feeVault] = anchor.web3.PublicKey.findProgramAddressSync(
+        [Buffer.from("fee_vault"), anchor.web3.PublicKey.default.toBuffer()],
+        program.programId
+    );
+    const globalAta = spl.getAssociatedTokenAddressSync(
+        tokenMint,
+        globalVault,
+        true
+    );
+    return { tokenMint, bondingCurve, globalVault, feeConfig, feeVault, globalAta };
+};
+
+/// swap_v2 on a curve from launchCurve, tokens go to and come from the trader's ATA
+export const swapV2 = async (
+    program: Program<Autofun>,
+    curve: ReturnType<typeof autofunAccounts>,
+    trader: anchor.web3.Keypair,
+    direction: any,
+    amount: BN
+) => {
+    const traderAta = spl.getAssociatedTokenAddressSync(
+        curve.tokenMint,
+        trader.publicKey
+    );
+    return program.methods
+        .swapV2({
+            direction,
+            amount,
+            minimumReceiveAmount: new BN(0),
+            deadline: new BN(Math.floor(Date.now() / 1000) + 120),
+            recipient: null,
+            referrer: null,
+        })
+        .accountsPartial({
+            feeConfig: curve.feeConfig,
+            bondingCurve: curve.bondingCurve,
+            feeVault: curve.feeVault,
+            globalVault: curve.globalVault,
+            tokenMint: curve.tokenMint,
+            globalAta: curve.globalAta,
+            userTokenAccount: traderAta,
+            recipient: trader.publicKey,
+            recipientTokenAccount: traderAta,
+            user: trader.publicKey,
+        })
+        .signers([trader])
+        .rpc({ commitment: "confirmed" });
+};
+
+/// Compute units consumed by a confirmed transaction
+export const computeUnits = async (signature: string) => {
+    const tx = await connection.getTransaction(signature, {
+        commitment: "confirmed",
+        maxSupportedTransactionVersion: 0,
+    });
+    return tx.meta.computeUnitsConsumed;
+};
+
+export const lockingProgram = new anchor.web3.PublicKey(
+    "LockrWmn6K5twhz3y9w1dQERbmgSaRkfnTeTKbpofwE"
+);
+export const lockedCpLiquiditySeed = "locked_liquidity";
+
+/// Loads the Raydium SDK against the test connection
+export const loadRaydium = (owner: anchor.web3.Keypair) =>
+    Raydium.load({
+        owner,
+        connection,
+        cluster: "mainnet",
+        disableFeatureCheck: true,
+        disableLoadToken: false,
+        blockhashCommitment: "finalized",
+    });
+
+/// Accounts shared by claim, claim_as_sol, compound and claim_many for a position locked in a
+/// CPMM pool. Creates the claimer's token accounts if needed, the vault token accounts are
+/// left to the first claim
+export const vaultPosition = async (
+    program: Program<RaydiumVault>,
+    payer: anchor.web3.Keypair,
+    raydium: Raydium,
+    positionNft: anchor.web3.PublicKey,
+    poolId: anchor.web3.PublicKey
+) => {
+    const [vaultConfig] = anchor.web3.PublicKey.findProgramAddressSync(
+        [Buffer.from(vaultConfigSeed)],
+        program.programId
+    );
+    const [userPosition] = anchor.web3.PublicKey.findProgramAddressSync(
+        [Buffer.from(positionSeed), positionNft.toBuffer()],
+        program.programId
+    );
+    const [feeNftAccount] = anchor.web3.PublicKey.findProgramAddressSync(
+        [Buffer.from(nftFaucetSeed), positionNft.toBuffer()],
+        program.programId
+    );
+    const [lockedAuthority] = anchor.web3.PublicKey.findProgramAddressSync(
+        [Buffer.from("lock_cp_authority_seed")],
+        lockingProgram
+    );
+    const [lockedLiquidity] = anchor.web3.PublicKey.findProgramAddressSync(
+        [Buffer.from(lockedCpLiquiditySeed), positionNft.toBuffer()],
+        lockingProgram
+    );
+    const cpmmProgram = CREATE_CPMM_POOL_PROGRAM;
+    const [cpAuthority] = anchor.web3.PublicKey.findProgramAddressSync(
+        [Buffer.from("vault_and_lp_mint_auth_seed")],
+        cpmmProgram
+    );
+
+    const poolInfo = (await raydium.api.fetchPoolById({ ids: poolId.toString() }))[0];
+    const cpmmPoolKey = await raydium.cpmm.getCpmmPoolKeys(poolId.toString());
+    const lpMint = new anchor.web3.PublicKey(poolInfo.lpMint.address);
+    const vault0Mint = new anchor.web3.PublicKey(poolInfo.mintA.address);
+    const vault1Mint = new anchor.web3.PublicKey(poolInfo.mintB.address);
+    const token0Program = (await connection.getAccountInfo(vault0Mint)).owner;
+    const token1Program = (await connection.getAccountInfo(vault1Mint)).owner;
+
+    const position = await program.account.userPosition.fetch(userPosition);
+    const recipientAccount = async (mint: anchor.web3.PublicKey, tokenProgram: anchor.web3.PublicKey) =>
+        (await spl.getOrCreateAssociatedTokenAccount(
+            connection,
+            payer,
+            mint,
+            position.claimer,
+            true,
+            "confirmed",
+            undefined,
+            tokenProgram
+        )).address;
+
+    return {
+        position,
+        cpmmPoolKey,
+        accounts: {
+            vaultConfig,
+            userPosition,
+            lockingProgram,
+            lockedAuthority,
+            feeNftAccount,
+            lockedLiquidity,
+            cpmmProgram,
+            cpAuthority,
+            poolState: poolId,
+            lpMint,
+            recipientToken0Account: await recipientAccount(vault0Mint, token0Program),
+            recipientToken1Account: await recipientAccount(vault1Mint, token1Program),
+            vaultToken0Account: spl.getAssociatedTokenAddressSync(vault0Mint, vaultConfig, true, token0Program),
+            vaultToken1Account: spl.getAssociatedTokenAddressSync(vault1Mint, vaultConfig, true, token1Program),
+            protocolToken0Account: null, // required once a protocol fee is set
+            protocolToken1Account: null,
+            token0Vault: new anchor.web3.PublicKey(cpmmPoolKey.vault.A),
+            token1Vault: new anchor.web3.PublicKey(cpmmPoolKey.vault.B),
+            vault0Mint,
+            vault1Mint,
+            lockedLpVault: spl.getAssociatedTokenAddressSync(lpMint, lockedAuthority, true),
+            tokenProgram: spl.TOKEN_PROGRAM_ID,
+            tokenProgram2022: spl.TOKEN_2022_PROGRAM_ID,
+            token0Program,
+            token1Program,
+            memoProgram: MEMO_PROGRAM_ID,
+            associatedTokenProgram: spl.ASSOCIATED_TOKEN_PROGRAM_ID,
+            systemProgram: SystemProgram.programId,
+        },
+    };
+};
+
+/// Token balance of an account, 0 if it does not exist yet
+export const tokenBalance = async (account: anchor.web3.PublicKey) => {
+    const info = await connection.getTokenAccountBalance(account).catch(() => null);
+    return new BN(info?.value.amount ?? 0);
+};
+
 export const isDevnet = (connection: anchor.web3.Connection): boolean => {
     return connection.rpcEndpoint == devnetEndpoint;
 };
@@ -127,11 +402,22 @@ export const isDevnet = (connection: anchor.web3.Connection): boolean => {
 export const utils = {
     sendSolTo,
     sendTokenTo,
+    fundWallet,
+    createAta,
+    launchCurve,
+    autofunAccounts,
+    swapV2,
+    computeUnits,
+    loadRaydium,
+    vaultPosition,
+    tokenBalance,
     isDevnet,
     getNftAddress,
     vaultConfigSeed,
     positionSeed,
     claimerInfoSeed,
     nftFaucetSeed,
+    solClaimSeed,
+    emergencyWithdrawSeed,
     claimer_address_0,
 };
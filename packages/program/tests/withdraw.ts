@@ -1,6 +1,7 @@
 import * as anchor from "@coral-xyz/anchor";
 import * as spl from "@solana/spl-token";
 import { Program } from "@coral-xyz/anchor";
+import { assert } from "chai";
 import { RaydiumVault } from "../target/types/raydium_vault";
 import NodeWallet from "@coral-xyz/anchor/dist/cjs/nodewallet";
 import {
@@ -8,6 +9,7 @@ import {
     isDevnet,
     nftFaucetSeed,
     positionSeed,
+    emergencyWithdrawSeed,
     vaultConfigSeed,
 } from "./utils";
 
@@ -36,25 +38,111 @@ describe("raydium_vault", () => {
             [Buffer.from(positionSeed), position_nft.toBuffer()],
             program.programId
         );
-        const to_account = spl.getAssociatedTokenAddressSync(
+        // The nft can only go to the claimer or the manager-approved recovery address
+        const position = await program.account.userPosition.fetch(user_position);
+        const to_owner = process.env.RECOVERY_ADDRESS
+            ? new anchor.web3.PublicKey(process.env.RECOVERY_ADDRESS)
+            : position.claimer;
+        const to_account = (await spl.getOrCreateAssociatedTokenAccount(
+            connection,
+            signerWallet,
             position_nft,
-            signerWallet.publicKey
-        );
+            to_owner,
+            true
+        )).address;
         const [nft_token_faucet] = anchor.web3.PublicKey.findProgramAddressSync(
             [Buffer.from(nftFaucetSeed), position_nft.toBuffer()],
             program.programId
         );
 
-        await program.rpc.emergencyWithdraw({
-            accounts: {
-                authority: signerWallet.publicKey,
-                vaultConfig: vault_config,
-                userPosition: user_position,
-                positionNft: position_nft,
-                toAccount: to_account,
-                nftTokenFaucet: nft_token_faucet,
-                tokenProgram: spl.TOKEN_PROGRAM_ID,
-            },
-        });
+        const [withdraw_request] = anchor.web3.PublicKey.findProgramAddressSync(
+            [Buffer.from(emergencyWithdrawSeed), position_nft.toBuffer()],
+            program.programId
+        );
+
+        const requestEmergencyWithdraw = () =>
+            program.methods
+                .requestEmergencyWithdraw(Number(process.env.REASON ?? 0))
+                .accountsPartial({
+                    authority: signerWallet.publicKey,
+                    vaultConfig: vault_config,
+                    userPosition: user_position,
+                    positionNft: position_nft,
+                    toAccount: to_account,
+                    withdrawRequest: withdraw_request,
+                })
+                .rpc({ commitment: "confirmed" });
+        const cancelEmergencyWithdraw = () =>
+            program.methods
+                .cancelEmergencyWithdraw()
+                .accountsPartial({
+                    authority: signerWallet.publicKey, // emergency authority
+                    vaultConfig: vault_config,
+                    userPosition: user_position,
+                    positionNft: position_nft,
+                    withdrawRequest: withdraw_request,
+                })
+                .rpc({ commitment: "confirmed" });
+
+        // Emergency withdraws are two-phase: request, then execute once the delay has passed.
+        // PHASE=veto lets the claimer drop a request, which blocks new requests for another delay.
+        // PHASE=cancel lets the emergency authority drop its own request, with no cooldown.
+        // PHASE=config sets the delay, 1 to 30 days, and the recovery address.
+        const phase = process.env.PHASE ?? "request";
+        if (phase === "config") {
+            await program.methods
+                .setEmergencyWithdrawConfig(
+                    new anchor.BN(process.env.DELAY ?? 24 * 60 * 60),
+                    new anchor.web3.PublicKey(
+                        process.env.RECOVERY_ADDRESS ?? anchor.web3.PublicKey.default
+                    )
+                )
+                .accounts({
+                    authority: signerWallet.publicKey, // manager authority
+                })
+                .rpc();
+        } else if (phase === "request") {
+            await requestEmergencyWithdraw();
+            const request = await program.account.emergencyWithdrawRequest.fetch(
+                withdraw_request
+            );
+            console.log("Executable at:", request.executableAt.toString());
+        } else if (phase === "veto") {
+            await program.methods
+                .vetoEmergencyWithdraw()
+                .accountsPartial({
+                    claimer: signerWallet.publicKey,
+                    userPosition: user_position,
+                    positionNft: position_nft,
+                    withdrawRequest: withdraw_request,
+                })
+                .rpc();
+            const position = await program.account.userPosition.fetch(user_position);
+            console.log("Vetoed at:", position.lastVetoedAt.toString());
+        } else if (phase === "cancel") {
+            await cancelEmergencyWithdraw();
+            assert.isNull(await connection.getAccountInfo(withdraw_request));
+            const after = await program.account.userPosition.fetch(user_position);
+            assert.equal(after.lastVetoedAt.toString(), position.lastVetoedAt.toString());
+
+            // No cooldown, so a new request can be made right away
+            await requestEmergencyWithdraw();
+            await cancelEmergencyWithdraw();
+            assert.isNull(await connection.getAccountInfo(withdraw_request));
+        } else {
+            await program.methods
+                .executeEmergencyWithdraw()
+                .accountsPartial({
+                    authority: signerWallet.publicKey,
+                    vaultConfig: vault_config,
+                    userPosition: user_position,
+                    positionNft: position_nft,
+                    nftTokenFaucet: nft_token_faucet,
+                    toAccount: to_account,
+                    withdrawRequest: withdraw_request,
+                    tokenProgram: spl.TOKEN_PROGRAM_ID,
+                })
+                .rpc();
+        }
     });
 });
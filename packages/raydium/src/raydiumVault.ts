@@ -245,6 +245,9 @@ export async function claim(
     true,
     spl.TOKEN_PROGRAM_ID
   );
+  // Token programs of the pool mints, the claim creates the vault's token accounts with them
+  const token0_program = (await connection.getAccountInfo(vault0_mint))!.owner;
+  const token1_program = (await connection.getAccountInfo(vault1_mint))!.owner;
   const locked_lp_vault = spl.getAssociatedTokenAddressSync(
     lp_mint,
     locked_authority,
@@ -276,6 +279,8 @@ export async function claim(
     vault1Mint: vault1_mint,
     lockedLpVault: locked_lp_vault,
     tokenProgram2022: spl.TOKEN_2022_PROGRAM_ID,
+    token0Program: token0_program,
+    token1Program: token1_program,
     memoProgram: raydium_api.MEMO_PROGRAM_ID,
   };
   const call = program.methods.claim().accounts(accounts);
@@ -3,6 +3,8 @@ import { fetchDigitalAsset } from "@metaplex-foundation/mpl-token-metadata";
 import { publicKey, Umi } from "@metaplex-foundation/umi";
 import {
   ASSOCIATED_TOKEN_PROGRAM_ID,
+  createAssociatedTokenAccountIdempotentInstruction,
+  getAssociatedTokenAddressSync,
   TOKEN_PROGRAM_ID,
 } from "@solana/spl-token";
 import {
@@ -27,6 +29,7 @@ import {
 
 export const SEED_BONDING_CURVE = "bonding_curve";
 export const SEED_CONFIG = "config";
+export const SEED_FEE_CONFIG = "fee_config";
 
 import { Wallet } from "./tokenSupplyHelpers/customWallet";
 /**
@@ -397,11 +400,11 @@ export const swapTx = async (
   connection: Connection,
   program: Program<Autofun>
 ) => {
-  const [configPda, _] = PublicKey.findProgramAddressSync(
-    [Buffer.from(SEED_CONFIG)],
+  const [feeConfigPda, _] = PublicKey.findProgramAddressSync(
+    [Buffer.from(SEED_FEE_CONFIG)],
     program.programId
   );
-  const configAccount = await program.account.config.fetch(configPda);
+  const feeConfigAccount = await program.account.feeConfig.fetch(feeConfigPda);
   const [bondingCurvePda] = PublicKey.findProgramAddressSync(
     [Buffer.from(SEED_BONDING_CURVE), token.toBytes()],
     program.programId
@@ -411,8 +414,8 @@ export const swapTx = async (
   // Apply platform fee
   const feePercent =
     style === 1
-      ? Number(configAccount.platformSellFee)
-      : Number(configAccount.platformBuyFee);
+      ? Number(feeConfigAccount.platformSellFee)
+      : Number(feeConfigAccount.platformBuyFee);
   const adjustedAmount = Math.floor(
     (amount * (FEE_BASIS_POINTS - feePercent)) / FEE_BASIS_POINTS
   );
@@ -455,13 +458,24 @@ export const swapTx = async (
 
   const deadline = Math.floor(Date.now() / 1000) + 120;
 
+  // swap takes any token account of the user, use the ATA and create it on a first buy
+  const userTokenAccount = getAssociatedTokenAddressSync(token, user);
+
   const tx = await program.methods
     .swap(new BN(amount), style, minOutput, new BN(deadline))
     .accounts({
-      teamWallet: configAccount.teamWallet,
       user,
       tokenMint: token,
+      userTokenAccount,
     })
+    .preInstructions([
+      createAssociatedTokenAccountIdempotentInstruction(
+        user,
+        userTokenAccount,
+        user,
+        token
+      ),
+    ])
     .transaction();
 
   tx.feePayer = user;